@@ -0,0 +1,53 @@
+use caretta_id::CollisionEstimator;
+use clap::{ArgGroup, Args};
+
+use crate::cli::length_option::{LengthOption, LengthOptions};
+
+/// Estimate collision probability and capacity of random caretta-id.
+#[derive(Args, Debug)]
+#[command(group(
+    ArgGroup::new("estimation")
+        .required(true)
+        .multiple(true)
+        .args(["count", "probability", "existing"])
+))]
+pub struct EstimateArgs {
+    /// Calculate the probability of collision among the number of generated IDs.
+    #[arg(short = 'n', long)]
+    count: Option<u64>,
+
+    /// Calculate the number of IDs that can be generated before the probability of collision is reached.
+    #[arg(short, long)]
+    probability: Option<f64>,
+
+    /// Calculate the expected number of retries to generate an unused ID against the number of existing IDs.
+    #[arg(short, long)]
+    existing: Option<u64>,
+
+    #[command(flatten)]
+    length: LengthOptions,
+}
+
+impl EstimateArgs {
+    pub fn run(self) {
+        let estimator = match LengthOption::from(self.length) {
+            LengthOption::Single => CollisionEstimator::CARETTA_ID_S,
+            LengthOption::Double => CollisionEstimator::CARETTA_ID_D,
+            LengthOption::Triple => CollisionEstimator::CARETTA_ID_T,
+            LengthOption::Quadruple => CollisionEstimator::CARETTA_ID_Q,
+            LengthOption::Unspecified => CollisionEstimator::CARETTA_ID,
+        };
+        if let Some(count) = self.count {
+            println!(
+                "collision probability: {}",
+                estimator.collision_probability(count)
+            );
+        }
+        if let Some(probability) = self.probability {
+            println!("max ids: {}", estimator.max_ids(probability));
+        }
+        if let Some(existing) = self.existing {
+            println!("expected retries: {}", estimator.expected_retries(existing));
+        }
+    }
+}
@@ -1,11 +1,14 @@
 mod decode;
 mod encode;
+mod estimate;
 mod generate;
 mod length_option;
 
 use clap::{Parser, Subcommand};
 
-use crate::cli::{decode::DecodeArgs, encode::EncodeArgs, generate::GenerateArgs};
+use crate::cli::{
+    decode::DecodeArgs, encode::EncodeArgs, estimate::EstimateArgs, generate::GenerateArgs,
+};
 
 #[derive(Debug, Parser)]
 #[command(version, about, long_about, infer_subcommands = true)]
@@ -24,6 +27,7 @@ impl Cli {
 pub enum CliSubcommand {
     Decode(DecodeArgs),
     Encode(EncodeArgs),
+    Estimate(EstimateArgs),
     Generate(GenerateArgs),
 }
 
@@ -32,6 +36,7 @@ impl CliSubcommand {
         match self {
             Self::Decode(x) => x.run(),
             Self::Encode(x) => x.run(),
+            Self::Estimate(x) => x.run(),
 
             Self::Generate(x) => x.run(),
         }
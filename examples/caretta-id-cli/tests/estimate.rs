@@ -0,0 +1,65 @@
+use std::{
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+use caretta_id::CollisionEstimator;
+
+fn run(args: &[&str]) -> Output {
+    let path = PathBuf::from(std::env!("CARGO_BIN_EXE_caretta-id-cli"));
+    Command::new(path)
+        .arg("estimate")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn estimate(args: &[&str]) -> String {
+    String::from_utf8(run(args).stdout).unwrap()
+}
+
+#[test]
+fn collision_probability() {
+    assert_eq!(
+        estimate(&["-n", "1000"]),
+        format!(
+            "collision probability: {}\n",
+            CollisionEstimator::CARETTA_ID.collision_probability(1000)
+        )
+    );
+}
+
+#[test]
+fn max_ids() {
+    assert_eq!(
+        estimate(&["-s", "-p", "0.5"]),
+        format!(
+            "max ids: {}\n",
+            CollisionEstimator::CARETTA_ID_S.max_ids(0.5)
+        )
+    );
+}
+
+#[test]
+fn all() {
+    let estimator = CollisionEstimator::CARETTA_ID_T;
+    assert_eq!(
+        estimate(&["-t", "-n", "10", "-p", "0.1", "-e", "100"]),
+        format!(
+            "collision probability: {}\nmax ids: {}\nexpected retries: {}\n",
+            estimator.collision_probability(10),
+            estimator.max_ids(0.1),
+            estimator.expected_retries(100)
+        )
+    );
+}
+
+#[test]
+fn missing_estimation() {
+    let output = run(&[]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("the following required arguments were not provided"));
+    assert!(stderr.contains("Usage: caretta-id-cli estimate"));
+}
@@ -232,6 +232,11 @@ pub(crate) fn char_to_u8(c: char) -> Option<u8> {
     }
 }
 
+/// Test if the character is a delimiter in [`DECODE_DELIMITER_TABLE`].
+pub(crate) fn char_is_delimiter(c: char) -> bool {
+    u8::try_from(c).is_ok_and(|x| DECODE_DELIMITER_TABLE[x as usize])
+}
+
 /// Test if the character is valid delimiter.
 #[deprecated(since = "0.8.0")]
 pub(crate) fn is_delimiter(c: char) -> bool {
@@ -0,0 +1,175 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{CarettaId, CarettaIdVariant, Error, alphabet::char_is_delimiter};
+#[allow(deprecated)]
+use crate::{CarettaIdD, CarettaIdQ, CarettaIdS, CarettaIdT};
+
+/// Any of [`CarettaId`] and the legacy variants, detected from the textual representation.
+///
+/// This is intended for API boundaries which must accept every historical format during the deprecation of the legacy variants.
+///
+/// [`FromStr`] detects the variant as follows:
+///
+/// - Delimited triplets like `abc-def`, `abc-def-ghj` and `abc-def-ghj-kmn` are [`CarettaIdD`], [`CarettaIdT`] and [`CarettaIdQ`].
+/// - Otherwise, 7 characters undelimited or grouped like [`CarettaIdFormat::GROUPED`](crate::CarettaIdFormat::GROUPED),
+///   i.e. `123abcd` or `123-abcd`, are [`CarettaId`].
+///   Other groupings like `abc-def-g` are rejected as they are easily confused with the legacy variants.
+/// - Otherwise, undelimited 3, 6, 9 and 12 characters are [`CarettaIdS`], [`CarettaIdD`], [`CarettaIdT`] and [`CarettaIdQ`].
+///
+/// [`Display`] and `serde` keep the format of the detected variant.
+/// `serde` always uses the textual representation so that the variant is kept even in binary formats.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(deprecated)]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let id: AnyCarettaId = "abc-def".parse()?;
+/// assert_eq!(id, AnyCarettaId::CarettaIdD("abc-def".parse()?));
+/// assert_eq!(id.to_string(), "abc-def");
+/// assert_eq!(CarettaId::try_from(id)?, CarettaId::from_u64(id.to_u64())?);
+///
+/// let id: AnyCarettaId = "123abcd".parse()?;
+/// assert_eq!(id, AnyCarettaId::CarettaId("123abcd".parse()?));
+///
+/// // Too large for CarettaId.
+/// let id: AnyCarettaId = "zzz-zzz-zzz-zzz".parse()?;
+/// assert!(CarettaId::try_from(id).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(deprecated)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AnyCarettaId {
+    CarettaId(CarettaId),
+    CarettaIdS(CarettaIdS),
+    CarettaIdD(CarettaIdD),
+    CarettaIdT(CarettaIdT),
+    CarettaIdQ(CarettaIdQ),
+}
+
+#[allow(deprecated)]
+impl AnyCarettaId {
+    /// Returns `true` if the id is one of the legacy variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// assert!("abc".parse::<AnyCarettaId>()?.is_legacy());
+    /// assert!(!"abc-defg".parse::<AnyCarettaId>()?.is_legacy());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_legacy(&self) -> bool {
+        !matches!(self, Self::CarettaId(_))
+    }
+
+    /// Returns the integer value of the id.
+    pub fn to_u64(self) -> u64 {
+        match self {
+            Self::CarettaId(x) => CarettaIdVariant::to_uint(x),
+            Self::CarettaIdS(x) => CarettaIdVariant::to_uint(x).into(),
+            Self::CarettaIdD(x) => CarettaIdVariant::to_uint(x).into(),
+            Self::CarettaIdT(x) => CarettaIdVariant::to_uint(x),
+            Self::CarettaIdQ(x) => CarettaIdVariant::to_uint(x),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl Display for AnyCarettaId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CarettaId(x) => Display::fmt(x, f),
+            Self::CarettaIdS(x) => Display::fmt(x, f),
+            Self::CarettaIdD(x) => Display::fmt(x, f),
+            Self::CarettaIdT(x) => Display::fmt(x, f),
+            Self::CarettaIdQ(x) => Display::fmt(x, f),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl FromStr for AnyCarettaId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = 0;
+        let mut triplets = true;
+        for segment in s.split(char_is_delimiter) {
+            segments += 1;
+            triplets &= segment.len() == 3;
+        }
+        if triplets && segments > 1 {
+            return match segments {
+                2 => s.parse().map(Self::CarettaIdD),
+                3 => s.parse().map(Self::CarettaIdT),
+                4 => s.parse().map(Self::CarettaIdQ),
+                _ => Err(Error::InvalidLength(s.len() - segments + 1)),
+            };
+        }
+        let len = s.chars().filter(|c| !char_is_delimiter(*c)).count();
+        match (len, segments) {
+            (7, 1) => s.parse().map(Self::CarettaId),
+            (7, _) => {
+                // Only a single delimiter after the first 3 characters is accepted, as `123-abcd`.
+                let mut delimiters = s.chars().enumerate().filter(|(_, c)| char_is_delimiter(*c));
+                let unexpected = match delimiters.next() {
+                    Some((3, _)) => delimiters.next(),
+                    x => x,
+                };
+                match unexpected {
+                    None => s.parse().map(Self::CarettaId),
+                    Some((index, character)) => Err(Error::InvalidCharacter { character, index }),
+                }
+            }
+            (3, 1) => s.parse().map(Self::CarettaIdS),
+            (6, 1) => s.parse().map(Self::CarettaIdD),
+            (9, 1) => s.parse().map(Self::CarettaIdT),
+            (12, 1) => s.parse().map(Self::CarettaIdQ),
+            (x, _) => Err(Error::InvalidLength(x)),
+        }
+    }
+}
+
+impl From<CarettaId> for AnyCarettaId {
+    fn from(value: CarettaId) -> Self {
+        Self::CarettaId(value)
+    }
+}
+
+macro_rules! impl_from_legacy {
+    ($($SelfT:ident),*) => {
+        $(
+            #[allow(deprecated)]
+            impl From<$SelfT> for AnyCarettaId {
+                fn from(value: $SelfT) -> Self {
+                    Self::$SelfT(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_legacy!(CarettaIdS, CarettaIdD, CarettaIdT, CarettaIdQ);
+
+impl TryFrom<AnyCarettaId> for CarettaId {
+    type Error = Error;
+
+    /// Converts keeping the value.
+    ///
+    /// Returns [`Error::ValueOutOfRange`] if the value is larger than [`CarettaId::MAX`].
+    #[allow(deprecated)]
+    fn try_from(value: AnyCarettaId) -> Result<Self, Self::Error> {
+        match value {
+            AnyCarettaId::CarettaId(x) => Ok(x),
+            AnyCarettaId::CarettaIdS(x) => CarettaId::try_from_variant(x),
+            AnyCarettaId::CarettaIdD(x) => CarettaId::try_from_variant(x),
+            AnyCarettaId::CarettaIdT(x) => CarettaId::try_from_variant(x),
+            AnyCarettaId::CarettaIdQ(x) => CarettaId::try_from_variant(x),
+        }
+    }
+}
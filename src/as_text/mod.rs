@@ -0,0 +1,65 @@
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+
+use core::fmt::Display;
+
+use crate::CarettaIdVariant;
+
+/// Wrapper of any [`CarettaIdVariant`] stored as its textual representation in SQL databases.
+///
+/// With `rusqlite` and `sea-orm` features, the id is written as a `TEXT` value like `'123abcd'` instead of an integer,
+/// so that tables can be inspected by humans and other tools.
+/// On read, both text and integer values are accepted, so columns with mixed legacy data can be migrated gradually.
+///
+/// Note that SQLite converts values on insert according to the type affinity of the column:
+/// integers are stored as decimal text in `TEXT` columns, and digit-only ids like `'0001234'` are stored as integers in `INTEGER` columns.
+/// Declare a column without type while it has mixed values.
+/// Since `sea-orm` declares `TEXT` columns for this type, convert integer rows before creating such columns from entities.
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "default", feature = "rusqlite"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "default", feature = "rusqlite")), doc = "```ignore")]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn = rusqlite::Connection::open_in_memory()?;
+/// conn.execute("CREATE TABLE note (id PRIMARY KEY)", ())?;
+///
+/// let id: CarettaId = "123abcd".parse()?;
+/// conn.execute("INSERT INTO note (id) VALUES (?1)", [AsText(id)])?;
+/// let text: String = conn.query_row("SELECT id FROM note", (), |row| row.get(0))?;
+/// assert_eq!(text, "123abcd");
+///
+/// // Integer values are accepted as well.
+/// conn.execute("INSERT INTO note (id) VALUES (?1)", [CarettaId::MAX])?;
+/// let ids = conn
+///     .prepare("SELECT id FROM note ORDER BY rowid")?
+///     .query_map((), |row| row.get::<_, AsText<CarettaId>>(0))?
+///     .collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(ids, [AsText(id), AsText(CarettaId::MAX)]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AsText<T: CarettaIdVariant>(pub T);
+
+impl<T: CarettaIdVariant> AsText<T> {
+    /// Returns the wrapped id.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: CarettaIdVariant> From<T> for AsText<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: CarettaIdVariant> Display for AsText<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,29 @@
+use rusqlite::{
+    ToSql,
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+};
+
+use super::AsText;
+use crate::{CarettaIdVariant, variant::variant_from_u64};
+
+impl<T: CarettaIdVariant> FromSql for AsText<T> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(int) => u64::try_from(int)
+                .ok()
+                .and_then(|x| variant_from_u64(x).ok())
+                .map(Self)
+                .ok_or(FromSqlError::OutOfRange(int)),
+            ValueRef::Text(_) => T::parse(value.as_str()?)
+                .map(Self)
+                .map_err(|e| FromSqlError::Other(Box::new(e))),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl<T: CarettaIdVariant> ToSql for AsText<T> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0.to_string()))
+    }
+}
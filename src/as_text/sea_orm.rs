@@ -0,0 +1,79 @@
+use sea_orm::{
+    TryFromU64, TryGetError,
+    sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr},
+};
+
+use super::AsText;
+use crate::{CarettaIdVariant, variant::variant_from_u64};
+
+impl<T: CarettaIdVariant> From<AsText<T>> for sea_orm::Value {
+    fn from(value: AsText<T>) -> Self {
+        value.0.to_string().into()
+    }
+}
+
+impl<T: CarettaIdVariant> sea_orm::TryGetable for AsText<T> {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        index: I,
+    ) -> Result<Self, TryGetError> {
+        let into_err = |from: &'static str, e: crate::Error| {
+            TryGetError::DbErr(sea_orm::DbErr::TryIntoErr {
+                from,
+                into: stringify!(AsText),
+                source: Box::new(e),
+            })
+        };
+        // Integer values are read only if the value is not a string, including null values of some backends.
+        match <String as sea_orm::TryGetable>::try_get_by(res, index) {
+            Ok(s) => T::parse(&s).map(Self).map_err(|e| into_err("String", e)),
+            Err(_) => <u64 as sea_orm::TryGetable>::try_get_by(res, index).and_then(|x| {
+                variant_from_u64(x)
+                    .map(Self)
+                    .map_err(|e| into_err("u64", e))
+            }),
+        }
+    }
+}
+
+impl<T: CarettaIdVariant> ValueType for AsText<T> {
+    fn try_from(v: sea_orm::Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            sea_orm::Value::String(Some(s)) => T::parse(&s).map(Self).map_err(|_| ValueTypeErr),
+            v => <u64 as ValueType>::try_from(v)
+                .and_then(|x| variant_from_u64(x).map(Self).map_err(|_| ValueTypeErr)),
+        }
+    }
+    fn type_name() -> String {
+        stringify!(AsText).to_owned()
+    }
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+    /// Returns [`ColumnType::Text`], so tables created from entities declare `TEXT` columns.
+    ///
+    /// Reading integer values is intended for columns without type affinity, e.g. declared without type in SQLite.
+    /// A `TEXT` column converts inserted integers to decimal text like `'35990925'`, which cannot be read back,
+    /// so existing integer rows must be converted to the textual representation before being stored in `TEXT` columns.
+    fn column_type() -> ColumnType {
+        ColumnType::Text
+    }
+}
+
+impl<T: CarettaIdVariant> Nullable for AsText<T> {
+    fn null() -> sea_orm::Value {
+        <String as Nullable>::null()
+    }
+}
+
+impl<T: CarettaIdVariant> TryFromU64 for AsText<T> {
+    fn try_from_u64(n: u64) -> Result<Self, sea_orm::DbErr> {
+        variant_from_u64(n)
+            .map(Self)
+            .map_err(|e| sea_orm::DbErr::TryIntoErr {
+                from: stringify!(u64),
+                into: stringify!(AsText),
+                source: Box::new(e),
+            })
+    }
+}
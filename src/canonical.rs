@@ -0,0 +1,140 @@
+#[cfg(feature = "std")]
+use crate::alphabet::DECODE_DELIMITER_TABLE;
+use crate::{
+    CarettaId, Error,
+    alphabet::{ENCODE_TABLE, char_to_u5},
+};
+
+impl CarettaId {
+    /// Parses a string to [`CarettaId`], accepting only the canonical form.
+    ///
+    /// Unlike [`FromStr`](core::str::FromStr), aliases (`i`, `l`, `o`, `u`), uppercase letters and delimiters are rejected,
+    /// so that every id has exactly one textual form, e.g. for URLs and database keys.
+    ///
+    /// # Error
+    ///
+    /// - [`Error::NonCanonicalCharacter`] with the canonical character if the character is an alias or uppercase.
+    /// - [`Error::InvalidCharacter`] if the character is not in the alphabet at all.
+    /// - [`Error::InvalidLength`] with the number of characters if it is not 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// assert_eq!(CarettaId::parse_strict("012atvw")?, "012atvw".parse()?);
+    /// assert_eq!(
+    ///     CarettaId::parse_strict("012aTvw"),
+    ///     Err(Error::NonCanonicalCharacter { character: 'T', index: 4, canonical: 't' })
+    /// );
+    /// assert_eq!(
+    ///     CarettaId::parse_strict("o12atvw"),
+    ///     Err(Error::NonCanonicalCharacter { character: 'o', index: 0, canonical: '0' })
+    /// );
+    /// assert_eq!(CarettaId::parse_strict("012-atvw"), Err(Error::InvalidCharacter { character: '-', index: 3 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Self, Error> {
+        let mut value = 0;
+        let mut len = 0;
+        for (index, character) in s.chars().enumerate() {
+            let u5 = char_to_u5(character).ok_or(Error::InvalidCharacter { character, index })?;
+            let canonical = ENCODE_TABLE[u5 as usize];
+            if character != canonical {
+                return Err(Error::NonCanonicalCharacter {
+                    character,
+                    index,
+                    canonical,
+                });
+            }
+            value = (value << 5) | u5 as u64;
+            len += 1;
+        }
+        if len == 7 {
+            Ok(Self::from_u64_lossy(value))
+        } else {
+            Err(Error::InvalidLength(len))
+        }
+    }
+
+    /// Test if the string is the canonical form of a [`CarettaId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// assert!(CarettaId::is_canonical("012atvw"));
+    /// assert!(!CarettaId::is_canonical("OL2ATUW"));
+    /// assert!(!CarettaId::is_canonical("012-atvw"));
+    /// assert!(!CarettaId::is_canonical("012atv!"));
+    /// ```
+    pub fn is_canonical(s: &str) -> bool {
+        Self::parse_strict(s).is_ok()
+    }
+
+    /// Parses a string same as [`FromStr`](core::str::FromStr), and reports how it differs from the canonical form.
+    ///
+    /// This is intended for web handlers to redirect non-canonical URLs to the canonical one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let result = CarettaId::canonicalize("OL2-atuw")?;
+    /// assert_eq!(result.canonical(), "012atvw");
+    /// assert_eq!(result.rewritten(), &[0, 1, 3, 6]);
+    /// assert!(!result.is_canonical());
+    ///
+    /// assert!(CarettaId::canonicalize("012atvw")?.is_canonical());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn canonicalize(s: &str) -> Result<Canonicalization, Error> {
+        let id: Self = s.parse()?;
+        let canonical = id.encode();
+        let mut expected = canonical.bytes();
+        let rewritten = s
+            .bytes()
+            .enumerate()
+            .filter(|(_, byte)| {
+                DECODE_DELIMITER_TABLE[*byte as usize] || expected.next() != Some(*byte)
+            })
+            .map(|(position, _)| position)
+            .collect();
+        Ok(Canonicalization { id, rewritten })
+    }
+}
+
+/// Result of [`CarettaId::canonicalize`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Canonicalization {
+    id: CarettaId,
+    rewritten: Vec<usize>,
+}
+
+#[cfg(feature = "std")]
+impl Canonicalization {
+    /// Returns the parsed id.
+    pub const fn id(&self) -> CarettaId {
+        self.id
+    }
+
+    /// Returns the canonical form of the id.
+    pub const fn canonical(&self) -> crate::EncodedCarettaId {
+        self.id.encode()
+    }
+
+    /// Returns the byte positions in the input that were replaced or removed (delimiters), in ascending order.
+    pub fn rewritten(&self) -> &[usize] {
+        &self.rewritten
+    }
+
+    /// Test if the input was already canonical.
+    pub fn is_canonical(&self) -> bool {
+        self.rewritten.is_empty()
+    }
+}
@@ -2,7 +2,7 @@ use core::{fmt::Display, str::FromStr};
 
 use crate::{
     Error,
-    alphabet::{BASE, u5_to_char_lossy},
+    alphabet::{BASE, DECODE_DELIMITER_TABLE, DECODE_TABLE},
 };
 
 /// Caretta id struct
@@ -188,22 +188,107 @@ impl CarettaId {
         self.0
     }
 
-    fn from_chars(value: [char; 7]) -> Result<Self, Error> {
-        Ok(Self::from_u64_unchecked(u5s_to_u35_unchecked(
-            chars_to_u5s(value)?,
-        )))
+    /// Parses ASCII bytes to [`CarettaId`] directly through [`DECODE_TABLE`], without UTF-8 validation.
+    ///
+    /// This accepts exactly the same inputs as [`FromStr`], which is built on this function:
+    /// aliases like `o`, uppercase letters and grouped forms like `123-ABCD`.
+    ///
+    /// # Error
+    ///
+    /// - [`Error::InvalidByte`] with the offending byte and its position if the slice contains a byte not in [`DECODE_TABLE`],
+    ///   or a delimiter which is leading, trailing, consecutive or different from the preceding delimiter.
+    /// - [`Error::InvalidLength`] with the number of bytes excluding delimiters if it is not 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// assert_eq!(CarettaId::parse_ascii(b"012atvw")?, "012atvw".parse()?);
+    /// assert_eq!(CarettaId::parse_ascii(b"012-ATVW")?, "012atvw".parse()?);
+    /// assert_eq!(CarettaId::parse_ascii(b"012atv"), Err(Error::InvalidLength(6)));
+    /// assert_eq!(
+    ///     CarettaId::parse_ascii(b"012--atvw"),
+    ///     Err(Error::InvalidByte { byte: b'-', position: 4 })
+    /// );
+    /// assert_eq!(
+    ///     CarettaId::parse_ascii(&[b'0', b'1', b'2', b'a', 0xff, b't', b'v']),
+    ///     Err(Error::InvalidByte { byte: 0xff, position: 4 })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn parse_ascii(bytes: &[u8]) -> Result<Self, Error> {
+        match decode(bytes) {
+            Ok(value) => Ok(Self::from_u64_unchecked(value)),
+            Err(DecodeError::InvalidLength(len)) => Err(Error::InvalidLength(len)),
+            Err(DecodeError::InvalidByte(position)) => Err(Error::InvalidByte {
+                byte: bytes[position],
+                position,
+            }),
+        }
     }
 
-    pub(crate) fn to_chars(self) -> [char; 7] {
-        [
-            u5_to_char_lossy((self.0 >> 30) as u8),
-            u5_to_char_lossy((self.0 >> 25) as u8),
-            u5_to_char_lossy((self.0 >> 20) as u8),
-            u5_to_char_lossy((self.0 >> 15) as u8),
-            u5_to_char_lossy((self.0 >> 10) as u8),
-            u5_to_char_lossy((self.0 >> 5) as u8),
-            u5_to_char_lossy((self.0) as u8),
-        ]
+    /// Parses a string to [`CarettaId`] in `const` context.
+    ///
+    /// This is the same as [`FromStr`] and [`parse_ascii`](Self::parse_ascii), so aliases like `o` and uppercase letters are accepted.
+    /// Delimiters `-` or `_` (see [`DECODE_DELIMITER_TABLE`]) are skipped if they separate groups of characters,
+    /// e.g. `123-ABCD` produced by [`CarettaIdFormat`](crate::CarettaIdFormat).
+    /// To validate literals at compile time, use [`caretta_id!`](crate::caretta_id!) macro.
+    ///
+    /// # Error
+    ///
+    /// - [`Error::InvalidCharacter`] if the string contains a character that is neither in [`DECODE_TABLE`] nor a delimiter,
+    ///   or a delimiter which is leading, trailing, consecutive or different from the preceding delimiter.
+    ///   Since all preceding characters are ASCII, the index is also the byte position.
+    /// - [`Error::InvalidLength`] with the number of non-delimiter characters if it is not 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// const ID: Result<CarettaId, Error> = CarettaId::try_parse("012atvw");
+    /// assert_eq!(ID?, "012atvw".parse()?);
+    /// assert_eq!(CarettaId::try_parse("012-ATVW")?, ID?);
+    ///
+    /// assert_eq!(CarettaId::try_parse("012atv"), Err(Error::InvalidLength(6)));
+    /// assert_eq!(CarettaId::try_parse("012-atvw-0"), Err(Error::InvalidLength(8)));
+    /// assert_eq!(
+    ///     CarettaId::try_parse("-012atvw"),
+    ///     Err(Error::InvalidCharacter { character: '-', index: 0 })
+    /// );
+    /// assert_eq!(
+    ///     CarettaId::try_parse("012!tvw"),
+    ///     Err(Error::InvalidCharacter { character: '!', index: 3 })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn try_parse(s: &str) -> Result<Self, Error> {
+        let bytes = s.as_bytes();
+        match decode(bytes) {
+            Ok(value) => Ok(Self::from_u64_unchecked(value)),
+            Err(DecodeError::InvalidLength(len)) => Err(Error::InvalidLength(len)),
+            Err(DecodeError::InvalidByte(index)) => Err(Error::InvalidCharacter {
+                character: char_at(bytes, index),
+                index,
+            }),
+        }
+    }
+
+    /// Parses a string literal for [`caretta_id!`](crate::caretta_id!) macro, panicking at compile time if invalid.
+    #[doc(hidden)]
+    pub const fn parse_literal(s: &str) -> Self {
+        match decode(s.as_bytes()) {
+            Ok(value) => Self::from_u64_unchecked(value),
+            Err(DecodeError::InvalidLength(_)) => {
+                panic!("caretta-id literal must be 7 characters")
+            }
+            Err(DecodeError::InvalidByte(_)) => {
+                panic!("caretta-id literal contains an invalid character")
+            }
+        }
     }
 
     #[doc = crate::macros::doc_to_bytes!("big endian")]
@@ -469,25 +554,69 @@ impl CarettaId {
     }
 }
 
-fn chars_to_u5s(value: [char; 7]) -> Result<[u8; 7], Error> {
-    let mut result = [0; 7];
-    for i in 0..7 {
-        result[i] = crate::alphabet::char_to_u5(value[i]).ok_or(Error::InvalidCharacter {
-            character: value[i],
-            index: i,
-        })?;
+#[derive(Clone, Copy)]
+enum DecodeError {
+    InvalidLength(usize),
+    InvalidByte(usize),
+}
+
+/// Decode bytes to integer, used by every parser of [`CarettaId`].
+///
+/// Delimiters in [`DECODE_DELIMITER_TABLE`] are skipped only if they are placed between characters one by one,
+/// and the same delimiter is used throughout, as the grouped forms of [`CarettaIdFormat`](crate::CarettaIdFormat).
+/// Other delimiters are reported as invalid bytes.
+const fn decode(bytes: &[u8]) -> Result<u64, DecodeError> {
+    let mut value = 0;
+    let mut len = 0;
+    let mut delimiter = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if DECODE_DELIMITER_TABLE[byte as usize] {
+            if i == 0
+                || i + 1 == bytes.len()
+                || DECODE_DELIMITER_TABLE[bytes[i - 1] as usize]
+                || (delimiter != 0 && delimiter != byte)
+            {
+                return Err(DecodeError::InvalidByte(i));
+            }
+            delimiter = byte;
+        } else {
+            match DECODE_TABLE[byte as usize] {
+                u8::MAX => return Err(DecodeError::InvalidByte(i)),
+                x => value = (value << 5) | x as u64,
+            }
+            len += 1;
+        }
+        i += 1;
+    }
+    if len == 7 {
+        Ok(value)
+    } else {
+        Err(DecodeError::InvalidLength(len))
     }
-    Ok(result)
 }
 
-fn u5s_to_u35_unchecked(value: [u8; 7]) -> u64 {
-    ((value[0] as u64) << 30)
-        | ((value[1] as u64) << 25)
-        | ((value[2] as u64) << 20)
-        | ((value[3] as u64) << 15)
-        | ((value[4] as u64) << 10)
-        | ((value[5] as u64) << 5)
-        | (value[6] as u64)
+/// Decode the character starting at the byte index.
+///
+/// All preceding bytes must be ASCII, so that the index is a character boundary.
+const fn char_at(bytes: &[u8], index: usize) -> char {
+    let lead = bytes[index];
+    let (len, mut code) = match lead {
+        0x00..0x80 => (1, lead as u32),
+        0x80..0xe0 => (2, (lead & 0x1f) as u32),
+        0xe0..0xf0 => (3, (lead & 0x0f) as u32),
+        _ => (4, (lead & 0x07) as u32),
+    };
+    let mut i = 1;
+    while i < len {
+        code = (code << 6) | (bytes[index + i] & 0x3f) as u32;
+        i += 1;
+    }
+    match char::from_u32(code) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
 }
 
 impl AsRef<u64> for CarettaId {
@@ -523,34 +652,73 @@ impl From<CarettaId> for u64 {
 }
 
 impl Display for CarettaId {
+    /// Formats the id, grouped like `123-abcd` if the alternate flag (`{:#}`) is set.
+    ///
+    /// See [`CarettaIdFormat`](crate::CarettaIdFormat) for other formats.
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let chars = self.to_chars();
-        write!(
-            f,
-            "{}{}{}{}{}{}{}",
-            chars[0], chars[1], chars[2], chars[3], chars[4], chars[5], chars[6]
-        )
+        if f.alternate() {
+            Display::fmt(&self.display_with(crate::CarettaIdFormat::GROUPED), f)
+        } else {
+            f.write_str(&self.encode())
+        }
     }
 }
 
 impl FromStr for CarettaId {
     type Err = Error;
 
+    /// Parses a string to [`CarettaId`]. See [`CarettaId::try_parse`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let len = s.len();
-        if len == 7 {
-            let mut chars = s.chars();
-            Self::from_chars([
-                chars.next().unwrap(),
-                chars.next().unwrap(),
-                chars.next().unwrap(),
-                chars.next().unwrap(),
-                chars.next().unwrap(),
-                chars.next().unwrap(),
-                chars.next().unwrap(),
-            ])
-        } else {
-            Err(Error::InvalidLength(len))
-        }
+        Self::try_parse(s)
+    }
+}
+
+impl TryFrom<&[u8; 7]> for CarettaId {
+    type Error = Error;
+
+    /// Parses ASCII bytes to [`CarettaId`]. See [`CarettaId::parse_ascii`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// assert_eq!(CarettaId::try_from(b"zzzzzzz")?, CarettaId::MAX);
+    /// assert_eq!(CarettaId::try_from(b"zzz-zzz"), Err(Error::InvalidLength(6)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_from(value: &[u8; 7]) -> Result<Self, Self::Error> {
+        Self::parse_ascii(value)
     }
 }
+
+/// Creates a [`CarettaId`] from a string literal, validated at compile time.
+///
+/// The result can be used in `const` and `static` items.
+///
+/// # Examples
+///
+/// ```
+/// use caretta_id::{CarettaId, caretta_id};
+///
+/// const SYSTEM: CarettaId = caretta_id!("012atvw");
+/// static ADMIN: CarettaId = caretta_id!("123abcd");
+///
+/// assert_eq!(SYSTEM, "012atvw".parse().unwrap());
+/// assert_eq!(ADMIN.to_u64(), 0x44352d8d);
+/// ```
+///
+/// Invalid literal fails to compile.
+///
+/// ```compile_fail
+/// # use caretta_id::{CarettaId, caretta_id};
+/// const INVALID: CarettaId = caretta_id!("012!tvw");
+/// ```
+#[macro_export]
+macro_rules! caretta_id {
+    ($s:expr) => {{
+        const ID: $crate::CarettaId = $crate::CarettaId::parse_literal($s);
+        ID
+    }};
+}
@@ -0,0 +1,151 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{
+    CarettaId, Error,
+    alphabet::{char_to_u5, u5_to_char_lossy},
+};
+
+/// [`CarettaId`] represented with an additional check character (e.g. `123abcd3`).
+///
+/// The 8th character is a checksum over the 7 characters of the [`CarettaId`],
+/// so parsing rejects any single-character substitution and any transposition of adjacent characters
+/// with [`Error::ChecksumMismatch`] instead of silently resolving to a different id.
+///
+/// The check character is the weighted sum of the 5-bit values in GF(2⁵),
+/// generated by the primitive polynomial `x⁵ + x² + 1` with the weights of the powers of `x`.
+/// See [SPECS.md](https://github.com/fluo10/caretta-id/blob/main/SPECS.md) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let id: CarettaId = "123abcd".parse()?;
+/// let checked = CheckedCarettaId::from(id);
+/// assert_eq!(checked.to_string(), "123abcd3");
+/// assert_eq!("123abcd3".parse::<CheckedCarettaId>()?.id(), id);
+///
+/// // Single-character substitution.
+/// assert!(matches!(
+///     "123abce3".parse::<CheckedCarettaId>(),
+///     Err(Error::ChecksumMismatch { .. })
+/// ));
+///
+/// // Transposition of adjacent characters.
+/// assert!(matches!(
+///     "132abcd3".parse::<CheckedCarettaId>(),
+///     Err(Error::ChecksumMismatch { .. })
+/// ));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CheckedCarettaId(CarettaId);
+
+impl CheckedCarettaId {
+    /// Creates a new [`CheckedCarettaId`].
+    pub const fn new(id: CarettaId) -> Self {
+        Self(id)
+    }
+
+    /// Returns the inner [`CarettaId`].
+    pub const fn id(self) -> CarettaId {
+        self.0
+    }
+
+    /// Returns the check character of the id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// assert_eq!(CheckedCarettaId::from(CarettaId::NIL).check_char(), '0');
+    /// assert_eq!("123abcd".parse::<CarettaId>()?.checked().check_char(), '3');
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn check_char(self) -> char {
+        u5_to_char_lossy(check_u5(self.0))
+    }
+}
+
+impl CarettaId {
+    /// Converts to a [`CheckedCarettaId`].
+    pub const fn checked(self) -> CheckedCarettaId {
+        CheckedCarettaId::new(self)
+    }
+}
+
+/// Multiply the 5-bit value by `x` in GF(2⁵) with the polynomial `x⁵ + x² + 1`.
+const fn mul_x(value: u8) -> u8 {
+    let shifted = value << 1;
+    if shifted & 0b100000 == 0 {
+        shifted
+    } else {
+        shifted ^ 0b100101
+    }
+}
+
+/// Calculate the check value so that `Σ dᵢ·x⁸⁻ⁱ` over the 8 characters equals zero.
+const fn check_u5(id: CarettaId) -> u8 {
+    let value = id.to_u64();
+    let mut interim = 0;
+    let mut i = 0;
+    while i < 7 {
+        interim = mul_x(interim) ^ ((value >> (30 - 5 * i)) as u8 & 0b11111);
+        i += 1;
+    }
+    mul_x(interim)
+}
+
+impl From<CarettaId> for CheckedCarettaId {
+    fn from(value: CarettaId) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<CheckedCarettaId> for CarettaId {
+    fn from(value: CheckedCarettaId) -> Self {
+        value.id()
+    }
+}
+
+impl Display for CheckedCarettaId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{}", self.0, self.check_char())
+    }
+}
+
+impl FromStr for CheckedCarettaId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((index, character)) = s.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+            return Err(Error::InvalidCharacter { character, index });
+        }
+        let len = s.len();
+        if len != 8 {
+            return Err(Error::InvalidCheckedLength(len));
+        }
+        // Delimiters are rejected, so that the check character is always the 8th character.
+        let mut u5s = [0; 8];
+        for (index, character) in s.chars().enumerate() {
+            u5s[index] =
+                char_to_u5(character).ok_or(Error::InvalidCharacter { character, index })?;
+        }
+        let value = u5s[..7]
+            .iter()
+            .fold(0, |value, u5| (value << 5) | *u5 as u64);
+        let checked = Self(CarettaId::from_u64_lossy(value));
+        let found = s.chars().nth(7).unwrap();
+        if u5s[7] == check_u5(checked.0) {
+            Ok(checked)
+        } else {
+            Err(Error::ChecksumMismatch {
+                expected: checked.check_char(),
+                found,
+            })
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{CarettaId, Error};
+
+/// Keyed bijection between sequential integers and [`CarettaId`].
+///
+/// This allows local auto-increment counters to be exposed as [`CarettaId`] without leaking insertion order or counts,
+/// while guaranteeing that different counters never collide.
+///
+/// Internally, this is a balanced Feistel network of 8 rounds over 36 bits,
+/// with cycle-walking so that results always stay below [`CarettaId::MAX`].
+/// Although results are not guessable without the key, this is an obfuscation and not a vetted block cipher.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let cipher = CarettaIdCipher::new([42; 16]);
+///
+/// let first = cipher.encrypt(1)?;
+/// let second = cipher.encrypt(2)?;
+/// assert_ne!(first, second);
+///
+/// assert_eq!(cipher.decrypt(first), 1);
+/// assert_eq!(cipher.decrypt(second), 2);
+///
+/// // Different key results in different id.
+/// assert_ne!(CarettaIdCipher::new([0; 16]).encrypt(1)?, first);
+///
+/// // Value larger than `CarettaId::MAX` cannot be encrypted.
+/// assert!(cipher.encrypt(CarettaId::MAX.to_u64() + 1).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct CarettaIdCipher {
+    round_keys: [u64; ROUNDS],
+}
+
+const ROUNDS: usize = 8;
+const HALF_BITS: u32 = 18;
+const HALF_MASK: u64 = (1 << HALF_BITS) - 1;
+
+impl CarettaIdCipher {
+    /// Creates a new cipher from the secret key.
+    pub const fn new(key: [u8; 16]) -> Self {
+        let mut state = u64::from_le_bytes([
+            key[0], key[1], key[2], key[3], key[4], key[5], key[6], key[7],
+        ]);
+        let tweak = u64::from_le_bytes([
+            key[8], key[9], key[10], key[11], key[12], key[13], key[14], key[15],
+        ]);
+        let mut round_keys = [0; ROUNDS];
+        let mut i = 0;
+        while i < ROUNDS {
+            state = state.wrapping_add(0x9e3779b97f4a7c15);
+            round_keys[i] = mix(state ^ mix(tweak.wrapping_add(i as u64)));
+            i += 1;
+        }
+        Self { round_keys }
+    }
+
+    /// Maps the integer to [`CarettaId`].
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::ValueOutOfRange`] if the value is larger than [`CarettaId::MAX`].
+    pub const fn encrypt(&self, value: u64) -> Result<CarettaId, Error> {
+        if value > CarettaId::MAX.to_u64() {
+            return Err(Error::ValueOutOfRange(value));
+        }
+        let mut x = self.permute(value);
+        while x > CarettaId::MAX.to_u64() {
+            x = self.permute(x);
+        }
+        Ok(CarettaId::from_u64_lossy(x))
+    }
+
+    /// Maps the [`CarettaId`] back to the integer.
+    ///
+    /// This is the inverse of [`encrypt`](Self::encrypt).
+    pub const fn decrypt(&self, id: CarettaId) -> u64 {
+        let mut x = self.inverse(id.to_u64());
+        while x > CarettaId::MAX.to_u64() {
+            x = self.inverse(x);
+        }
+        x
+    }
+
+    const fn permute(&self, value: u64) -> u64 {
+        let mut left = value >> HALF_BITS;
+        let mut right = value & HALF_MASK;
+        let mut i = 0;
+        while i < ROUNDS {
+            let next = left ^ round(self.round_keys[i], right);
+            left = right;
+            right = next;
+            i += 1;
+        }
+        (left << HALF_BITS) | right
+    }
+
+    const fn inverse(&self, value: u64) -> u64 {
+        let mut left = value >> HALF_BITS;
+        let mut right = value & HALF_MASK;
+        let mut i = ROUNDS;
+        while i > 0 {
+            i -= 1;
+            let previous = right ^ round(self.round_keys[i], left);
+            right = left;
+            left = previous;
+        }
+        (left << HALF_BITS) | right
+    }
+}
+
+impl core::fmt::Debug for CarettaIdCipher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CarettaIdCipher").finish_non_exhaustive()
+    }
+}
+
+/// Round function of the Feistel network.
+const fn round(key: u64, half: u64) -> u64 {
+    mix(key ^ half) & HALF_MASK
+}
+
+/// Finalizer of MurmurHash3.
+const fn mix(mut x: u64) -> u64 {
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51afd7ed558ccd);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xc4ceb9fe1a85ec53);
+    x ^= x >> 33;
+    x
+}
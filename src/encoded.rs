@@ -0,0 +1,151 @@
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::Deref,
+};
+
+use crate::{CarettaId, alphabet::ENCODE_TABLE};
+
+/// Stack-allocated string representation of [`CarettaId`], returned by [`CarettaId::encode`].
+///
+/// This dereferences to [`str`] without any allocation, so it is usable in `no_std` environments and hot paths.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let id: CarettaId = "123abcd".parse()?;
+/// let encoded = id.encode();
+///
+/// assert_eq!(&*encoded, "123abcd");
+/// assert_eq!(encoded.len(), 7);
+/// assert!(encoded.starts_with("123"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EncodedCarettaId([u8; 7]);
+
+impl EncodedCarettaId {
+    /// Returns the string slice.
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: every byte is an ASCII character taken from `ENCODE_TABLE`.
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Returns the ASCII bytes.
+    pub const fn as_bytes(&self) -> &[u8; 7] {
+        &self.0
+    }
+}
+
+impl CarettaId {
+    /// Encodes the id to a stack-allocated string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// assert_eq!(CarettaId::NIL.encode().as_str(), "0000000");
+    /// assert_eq!(CarettaId::MAX.encode().as_str(), "zzzzzzz");
+    /// ```
+    pub const fn encode(self) -> EncodedCarettaId {
+        let value = self.to_u64();
+        let mut buf = [0; 7];
+        let mut i = 0;
+        while i < 7 {
+            buf[i] = ENCODE_TABLE[(value >> (30 - 5 * i)) as usize & 0b11111] as u8;
+            i += 1;
+        }
+        EncodedCarettaId(buf)
+    }
+
+    /// Encodes the id into the first 7 bytes of the buffer and returns them as a string slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than 7 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut buf = [b'#'; 8];
+    /// let id: CarettaId = "123abcd".parse()?;
+    /// assert_eq!(id.encode_to_slice(&mut buf[1..]), "123abcd");
+    /// assert_eq!(&buf, b"#123abcd");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encode_to_slice(self, buf: &mut [u8]) -> &mut str {
+        let buf = &mut buf[..7];
+        buf.copy_from_slice(self.encode().as_bytes());
+        // SAFETY: every byte is an ASCII character taken from `ENCODE_TABLE`.
+        unsafe { core::str::from_utf8_unchecked_mut(buf) }
+    }
+}
+
+impl From<CarettaId> for EncodedCarettaId {
+    fn from(value: CarettaId) -> Self {
+        value.encode()
+    }
+}
+
+impl Deref for EncodedCarettaId {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for EncodedCarettaId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for EncodedCarettaId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<str> for EncodedCarettaId {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Hash for EncodedCarettaId {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // Must be consistent with `str` for `Borrow<str>`.
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for EncodedCarettaId {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for EncodedCarettaId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Debug for EncodedCarettaId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for EncodedCarettaId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
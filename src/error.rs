@@ -9,6 +9,36 @@ pub enum Error {
     InvalidLength(usize),
     #[error("Invalid character, alphanumeric expected, found {character} at {index}")]
     InvalidCharacter { character: char, index: usize },
+    #[error("Invalid byte, alphanumeric ASCII expected, found {byte:#04x} at {position}")]
+    InvalidByte { byte: u8, position: usize },
+    #[error("Non-canonical character, found {character} at {index}, expected {canonical}")]
+    NonCanonicalCharacter {
+        character: char,
+        index: usize,
+        canonical: char,
+    },
+    #[error("Invalid length, expected 8, found {0}")]
+    InvalidCheckedLength(usize),
+    #[error("Checksum mismatch, expected check character {expected}, found {found}")]
+    ChecksumMismatch { expected: char, found: char },
+    #[error("Invalid prefix, expected {expected}")]
+    InvalidPrefix { expected: &'static str },
+    #[error("Invalid bit width, found {0}")]
+    InvalidBitWidth(u32),
+    #[error("Invalid resolution, found {0}")]
+    InvalidResolution(u64),
+    #[error("Timestamp out of range, found {0}")]
+    TimestampOutOfRange(u64),
+    #[error("Monotonic overflow, random bits of the current tick are exhausted")]
+    MonotonicOverflow,
+    #[error("Counter overflow, local space of the node is exhausted")]
+    CounterOverflow,
+    #[error("Insufficient local bits, expected at least {required}, found {available}")]
+    InsufficientLocalBits { required: u32, available: u32 },
+    #[error("Invalid number of words, expected 4, found {0}")]
+    InvalidWordCount(usize),
+    #[error("Unknown word at {index}")]
+    UnknownWord { index: usize },
     #[deprecated(
         since = "0.8.0",
         note = "The ParseInteger has been renamed. Use ValueOfRange instead."
@@ -0,0 +1,97 @@
+/// Collision probability and capacity planning for random ids.
+///
+/// All estimations assume that ids are drawn uniformly at random, like [`CarettaId::random`](crate::CarettaId::random).
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// let estimator = CollisionEstimator::CARETTA_ID;
+///
+/// // About 1% chance of collision among 26,000 ids.
+/// let probability = estimator.collision_probability(26_000);
+/// assert!(0.009 < probability && probability < 0.011);
+///
+/// // Number of ids before 50% chance of collision.
+/// assert_eq!(estimator.max_ids(0.5), 218_249);
+///
+/// // Legacy variants.
+/// assert!(CollisionEstimator::CARETTA_ID_Q.collision_probability(26_000) < probability);
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CollisionEstimator {
+    capacity: u64,
+}
+
+impl CollisionEstimator {
+    /// Estimator for [`CarettaId`](crate::CarettaId) (35 bits).
+    pub const CARETTA_ID: Self = Self::from_bits(35);
+
+    /// Estimator for [`CarettaIdS`](crate::CarettaIdS) (15 bits).
+    pub const CARETTA_ID_S: Self = Self::from_bits(15);
+
+    /// Estimator for [`CarettaIdD`](crate::CarettaIdD) (30 bits).
+    pub const CARETTA_ID_D: Self = Self::from_bits(30);
+
+    /// Estimator for [`CarettaIdT`](crate::CarettaIdT) (45 bits).
+    pub const CARETTA_ID_T: Self = Self::from_bits(45);
+
+    /// Estimator for [`CarettaIdQ`](crate::CarettaIdQ) (60 bits).
+    pub const CARETTA_ID_Q: Self = Self::from_bits(60);
+
+    /// Creates an estimator for ids with the number of possible values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub const fn new(capacity: u64) -> Self {
+        assert!(capacity != 0, "capacity must not be zero");
+        Self { capacity }
+    }
+
+    /// Creates an estimator for ids with the bit width.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is 64 or more.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self::new(1 << bits)
+    }
+
+    /// Returns the number of possible values.
+    pub const fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// Returns the probability that at least two of `count` ids collide.
+    ///
+    /// This is the approximation of the birthday problem: `1 - exp(-n(n-1) / 2N)`.
+    pub fn collision_probability(&self, count: u64) -> f64 {
+        let n = count as f64;
+        -(-n * (n - 1.0) / (2.0 * self.capacity as f64)).exp_m1()
+    }
+
+    /// Returns the maximum number of ids that can be generated while the collision probability stays at `probability` or lower.
+    ///
+    /// This is the inverse of [`collision_probability`](Self::collision_probability).
+    /// `probability` is clamped between 0 and 1.
+    pub fn max_ids(&self, probability: f64) -> u64 {
+        let probability = probability.clamp(0.0, 1.0);
+        if probability >= 1.0 {
+            return self.capacity;
+        }
+        let x = -2.0 * self.capacity as f64 * (-probability).ln_1p();
+        let count = ((1.0 + (1.0 + 4.0 * x).sqrt()) / 2.0).floor() as u64;
+        count.clamp(1, self.capacity)
+    }
+
+    /// Returns the expected number of retries of random generation against `existing` ids to find an unused one.
+    ///
+    /// Returns [`f64::INFINITY`] if all values are used.
+    pub fn expected_retries(&self, existing: u64) -> f64 {
+        if existing >= self.capacity {
+            return f64::INFINITY;
+        }
+        existing as f64 / (self.capacity - existing) as f64
+    }
+}
@@ -0,0 +1,153 @@
+use core::fmt::{Display, UpperHex, Write};
+
+use crate::CarettaId;
+
+/// Formatting options of [`CarettaId`], such as case, grouping pattern and separator.
+///
+/// Grouped forms using `-` or `_` as separator (e.g. `123-ABCD`) can be parsed back with [`FromStr`](core::str::FromStr),
+/// while other separators like space are for display only.
+///
+/// The same formats are also available through [`Formatter`](core::fmt::Formatter) flags:
+///
+/// | Format string | Output     |
+/// |:--------------|:-----------|
+/// | `{}`          | `123abcd`  |
+/// | `{:#}`        | `123-abcd` |
+/// | `{:X}`        | `123ABCD`  |
+/// | `{:#X}`       | `123-ABCD` |
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let id: CarettaId = "123abcd".parse()?;
+///
+/// let label = CarettaIdFormat::new().uppercase(true).groups(&[3, 4]);
+/// assert_eq!(id.display_with(label).to_string(), "123-ABCD");
+///
+/// let phone = CarettaIdFormat::new().groups(&[3, 2, 2]).separator(' ');
+/// assert_eq!(id.display_with(phone).to_string(), "123 ab cd");
+///
+/// assert_eq!(format!("{id:#}"), "123-abcd");
+/// assert_eq!(format!("{id:X}"), "123ABCD");
+/// assert_eq!(format!("{id:#X}"), "123-ABCD");
+///
+/// // Grouped form can be parsed back.
+/// assert_eq!("123-ABCD".parse::<CarettaId>()?, id);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CarettaIdFormat {
+    uppercase: bool,
+    /// Bit `i` is set if the separator is inserted before `i`-th character.
+    breaks: u8,
+    separator: char,
+}
+
+impl CarettaIdFormat {
+    /// Lowercase without grouping, e.g. `123abcd`. Same as `{}`.
+    pub const PLAIN: Self = Self::new();
+
+    /// Lowercase grouped by 3 and 4 characters with `-`, e.g. `123-abcd`. Same as `{:#}`.
+    pub const GROUPED: Self = Self::new().groups(&[3, 4]);
+
+    /// Creates the format of lowercase without grouping, using `-` as separator once grouped.
+    pub const fn new() -> Self {
+        Self {
+            uppercase: false,
+            breaks: 0,
+            separator: '-',
+        }
+    }
+
+    /// Sets whether letters are uppercase.
+    pub const fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Sets the grouping pattern by the number of characters of each group from the start.
+    ///
+    /// Groups exceeding 7 characters are ignored, and empty pattern disables grouping.
+    pub const fn groups(mut self, sizes: &[usize]) -> Self {
+        self.breaks = 0;
+        let mut position = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            position += sizes[i];
+            if position >= 7 {
+                break;
+            }
+            if position > 0 {
+                self.breaks |= 1 << position;
+            }
+            i += 1;
+        }
+        self
+    }
+
+    /// Sets the separator between groups.
+    pub const fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Returns the displayable of the id in this format.
+    pub const fn display(self, id: CarettaId) -> CarettaIdDisplay {
+        CarettaIdDisplay { id, format: self }
+    }
+}
+
+impl Default for CarettaIdFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Displayable [`CarettaId`] with [`CarettaIdFormat`], returned by [`CarettaId::display_with`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CarettaIdDisplay {
+    id: CarettaId,
+    format: CarettaIdFormat,
+}
+
+impl Display for CarettaIdDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, c) in self.id.encode().chars().enumerate() {
+            if self.format.breaks & (1 << i) != 0 {
+                f.write_char(self.format.separator)?;
+            }
+            if self.format.uppercase {
+                f.write_char(c.to_ascii_uppercase())?;
+            } else {
+                f.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CarettaId {
+    /// Returns the displayable of the id in the format.
+    ///
+    /// See [`CarettaIdFormat`] for details.
+    pub const fn display_with(self, format: CarettaIdFormat) -> CarettaIdDisplay {
+        format.display(self)
+    }
+}
+
+impl UpperHex for CarettaId {
+    /// Formats the id in uppercase letters, grouped like `{:#}` if the alternate flag is set.
+    ///
+    /// Despite the name of the trait, the output is not hexadecimal.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let format = if f.alternate() {
+            CarettaIdFormat::GROUPED
+        } else {
+            CarettaIdFormat::PLAIN
+        };
+        Display::fmt(&self.display_with(format.uppercase(true)), f)
+    }
+}
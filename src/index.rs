@@ -0,0 +1,263 @@
+use core::ops::Range;
+
+use crate::{CarettaId, Error, alphabet::char_to_u5};
+
+/// Sorted set of [`CarettaId`] resolving abbreviated ids like short git hashes.
+///
+/// A prefix of 1 to 7 characters is decoded with the same alias rules as [`DECODE_TABLE`](crate::alphabet::DECODE_TABLE),
+/// so `"0l2a"` and `"012a"` resolve to the same id.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let index: CarettaIdIndex = ["123abcd", "123azzz", "456wxyz"]
+///     .into_iter()
+///     .map(|s| s.parse::<CarettaId>())
+///     .collect::<Result<_, _>>()?;
+///
+/// // Unique prefix resolves to the stored id.
+/// assert_eq!(index.resolve("456")?, "456wxyz".parse::<CarettaId>()?);
+///
+/// // Ambiguous prefix returns all candidates.
+/// assert_eq!(
+///     index.resolve("123a"),
+///     Err(ResolveError::Ambiguous(vec!["123abcd".parse()?, "123azzz".parse()?]))
+/// );
+///
+/// // Unknown prefix.
+/// assert_eq!(index.resolve("789"), Err(ResolveError::NotFound));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct CarettaIdIndex {
+    ids: Vec<CarettaId>,
+}
+
+impl CarettaIdIndex {
+    /// Creates an empty index.
+    pub const fn new() -> Self {
+        Self { ids: Vec::new() }
+    }
+
+    /// Returns the number of ids in the index.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if the index contains no ids.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns `true` if the index contains the id.
+    pub fn contains(&self, id: &CarettaId) -> bool {
+        self.ids.binary_search(id).is_ok()
+    }
+
+    /// Adds an id to the index.
+    ///
+    /// Returns `false` if the id was already present.
+    pub fn insert(&mut self, id: CarettaId) -> bool {
+        match self.ids.binary_search(&id) {
+            Ok(_) => false,
+            Err(i) => {
+                self.ids.insert(i, id);
+                true
+            }
+        }
+    }
+
+    /// Removes an id from the index.
+    ///
+    /// Returns `false` if the id was not present.
+    pub fn remove(&mut self, id: &CarettaId) -> bool {
+        match self.ids.binary_search(id) {
+            Ok(i) => {
+                self.ids.remove(i);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns an iterator over the stored ids in ascending order.
+    pub fn iter(&self) -> core::slice::Iter<'_, CarettaId> {
+        self.ids.iter()
+    }
+
+    /// Returns all stored ids starting with the prefix, in ascending order.
+    ///
+    /// # Error
+    ///
+    /// Returns error if the prefix is empty, longer than 7 characters or contains an invalid character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let index = CarettaIdIndex::from_iter([
+    ///     "123abcd".parse::<CarettaId>()?,
+    ///     "123azzz".parse::<CarettaId>()?,
+    /// ]);
+    /// assert_eq!(index.candidates("123")?.len(), 2);
+    /// assert_eq!(index.candidates("123ab")?.len(), 1);
+    /// assert!(index.candidates("456")?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn candidates(&self, prefix: &str) -> Result<&[CarettaId], Error> {
+        let range = prefix_to_range(prefix)?;
+        let start = self.ids.partition_point(|id| id.to_u64() < range.start);
+        let end = self.ids.partition_point(|id| id.to_u64() < range.end);
+        Ok(&self.ids[start..end])
+    }
+
+    /// Resolves the prefix to the unique stored id.
+    ///
+    /// # Error
+    ///
+    /// - [`ResolveError::NotFound`] if no stored id starts with the prefix.
+    /// - [`ResolveError::Ambiguous`] with every matching id if more than one stored id starts with the prefix.
+    /// - [`ResolveError::Invalid`] with [`Error::InvalidLength`] or [`Error::InvalidCharacter`] if the prefix itself is invalid.
+    pub fn resolve(&self, prefix: &str) -> Result<CarettaId, ResolveError> {
+        match self.candidates(prefix)? {
+            [] => Err(ResolveError::NotFound),
+            [id] => Ok(*id),
+            candidates => Err(ResolveError::Ambiguous(candidates.to_vec())),
+        }
+    }
+
+    /// Returns the length of the shortest prefix that uniquely identifies the stored id.
+    ///
+    /// Returns `None` if the id is not in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let a: CarettaId = "123abcd".parse()?;
+    /// let b: CarettaId = "123azzz".parse()?;
+    /// let index = CarettaIdIndex::from_iter([a, b]);
+    /// assert_eq!(index.unique_prefix_len(&a), Some(5));
+    /// assert_eq!(index.unique_prefix_len(&"456wxyz".parse()?), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unique_prefix_len(&self, id: &CarettaId) -> Option<usize> {
+        let i = self.ids.binary_search(id).ok()?;
+        let prev = i
+            .checked_sub(1)
+            .map_or(0, |j| common_prefix_len(self.ids[j], *id));
+        let next = self
+            .ids
+            .get(i + 1)
+            .map_or(0, |other| common_prefix_len(*other, *id));
+        Some(usize::max(prev, next) + 1)
+    }
+
+    /// Returns the shortest unique abbreviation of the stored id.
+    ///
+    /// The abbreviation is at least `min_len` characters long (clamped to 7), like the `--abbrev` option of git.
+    /// Returns `None` if the id is not in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let a: CarettaId = "123abcd".parse()?;
+    /// let index = CarettaIdIndex::from_iter([a, "123azzz".parse()?, "456wxyz".parse()?]);
+    /// assert_eq!(index.abbreviate(&a, 0).as_deref(), Some("123ab"));
+    /// assert_eq!(index.abbreviate(&"456wxyz".parse()?, 4).as_deref(), Some("456w"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn abbreviate(&self, id: &CarettaId, min_len: usize) -> Option<String> {
+        let len = usize::max(self.unique_prefix_len(id)?, min_len).min(7);
+        Some(id.encode()[..len].to_string())
+    }
+
+    /// Returns an iterator over every stored id paired with its shortest unique abbreviation.
+    ///
+    /// See [`abbreviate`](Self::abbreviate) for details of `min_len`.
+    pub fn abbreviations(&self, min_len: usize) -> impl Iterator<Item = (CarettaId, String)> + '_ {
+        self.ids
+            .iter()
+            .map(move |id| (*id, self.abbreviate(id, min_len).unwrap()))
+    }
+}
+
+impl FromIterator<CarettaId> for CarettaIdIndex {
+    fn from_iter<T: IntoIterator<Item = CarettaId>>(iter: T) -> Self {
+        let mut ids: Vec<CarettaId> = iter.into_iter().collect();
+        ids.sort_unstable();
+        ids.dedup();
+        Self { ids }
+    }
+}
+
+impl Extend<CarettaId> for CarettaIdIndex {
+    fn extend<T: IntoIterator<Item = CarettaId>>(&mut self, iter: T) {
+        self.ids.extend(iter);
+        self.ids.sort_unstable();
+        self.ids.dedup();
+    }
+}
+
+impl<'a> IntoIterator for &'a CarettaIdIndex {
+    type Item = &'a CarettaId;
+    type IntoIter = core::slice::Iter<'a, CarettaId>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for CarettaIdIndex {
+    type Item = CarettaId;
+    type IntoIter = std::vec::IntoIter<CarettaId>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.into_iter()
+    }
+}
+
+/// An error that can occur when resolving a prefix with [`CarettaIdIndex::resolve`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+pub enum ResolveError {
+    #[error("Invalid prefix: {0}")]
+    Invalid(#[from] Error),
+    #[error("No caretta-id matches the prefix")]
+    NotFound,
+    #[error("Ambiguous prefix, {} caretta-ids match", .0.len())]
+    Ambiguous(Vec<CarettaId>),
+}
+
+/// Decode prefix to the range of integer values sharing it.
+fn prefix_to_range(prefix: &str) -> Result<Range<u64>, Error> {
+    let mut value: u64 = 0;
+    let mut len = 0;
+    for (index, character) in prefix.chars().enumerate() {
+        if index >= 7 {
+            return Err(Error::InvalidLength(prefix.chars().count()));
+        }
+        let u5 = char_to_u5(character).ok_or(Error::InvalidCharacter { character, index })?;
+        value = (value << 5) | u5 as u64;
+        len += 1;
+    }
+    if len == 0 {
+        return Err(Error::InvalidLength(0));
+    }
+    let shift = 5 * (7 - len);
+    Ok((value << shift)..((value + 1) << shift))
+}
+
+/// Count the leading characters shared by two ids.
+fn common_prefix_len(a: CarettaId, b: CarettaId) -> usize {
+    let diff = a.to_u64() ^ b.to_u64();
+    let leading_bits = diff.leading_zeros() - (u64::BITS - CarettaId::BITS);
+    usize::min((leading_bits / 5) as usize, 7)
+}
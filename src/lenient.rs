@@ -0,0 +1,111 @@
+use crate::{CarettaId, Error, alphabet::char_to_u5};
+
+/// Result of folding an input character into the canonical alphabet.
+enum Folded {
+    Char(char),
+    Skip,
+}
+
+/// Fold full-width forms, homoglyphs, whitespace and dashes into ASCII.
+fn fold(c: char) -> Folded {
+    match c {
+        // Hyphen and underscore delimiters, dashes and prolonged sound marks typed as hyphens.
+        '-' | '_' | '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{30fc}' | '\u{ff70}' => Folded::Skip,
+        // Zero-width characters and byte order mark.
+        '\u{200b}'..='\u{200d}' | '\u{2060}' | '\u{feff}' => Folded::Skip,
+        c if c.is_whitespace() => Folded::Skip,
+        // Full-width ASCII.
+        '\u{ff01}'..='\u{ff5e}' => match fold(char::from_u32(c as u32 - 0xfee0).unwrap()) {
+            Folded::Char(c) => Folded::Char(c),
+            Folded::Skip => Folded::Skip,
+        },
+        // Cyrillic homoglyphs.
+        'а' | 'А' => Folded::Char('a'),
+        'В' | 'в' => Folded::Char('b'),
+        'с' | 'С' => Folded::Char('c'),
+        'ԁ' => Folded::Char('d'),
+        'е' | 'Е' => Folded::Char('e'),
+        'һ' | 'Н' | 'н' => Folded::Char('h'),
+        'і' | 'І' => Folded::Char('i'),
+        'ј' | 'Ј' => Folded::Char('j'),
+        'к' | 'К' => Folded::Char('k'),
+        'м' | 'М' => Folded::Char('m'),
+        'о' | 'О' => Folded::Char('o'),
+        'р' | 'Р' => Folded::Char('p'),
+        'ѕ' | 'Ѕ' => Folded::Char('s'),
+        'т' | 'Т' => Folded::Char('t'),
+        'х' | 'Х' => Folded::Char('x'),
+        'у' | 'У' => Folded::Char('y'),
+        // Greek homoglyphs.
+        'α' | 'Α' => Folded::Char('a'),
+        'Β' => Folded::Char('b'),
+        'Ε' => Folded::Char('e'),
+        'Η' => Folded::Char('h'),
+        'ι' | 'Ι' => Folded::Char('i'),
+        'Κ' => Folded::Char('k'),
+        'Μ' => Folded::Char('m'),
+        'Ν' => Folded::Char('n'),
+        'ο' | 'Ο' => Folded::Char('o'),
+        'Ρ' => Folded::Char('p'),
+        'Τ' => Folded::Char('t'),
+        'υ' => Folded::Char('u'),
+        'ν' => Folded::Char('v'),
+        'Χ' => Folded::Char('x'),
+        'Υ' => Folded::Char('y'),
+        'Ζ' => Folded::Char('z'),
+        c => Folded::Char(c),
+    }
+}
+
+impl CarettaId {
+    /// Parses a string to [`CarettaId`] leniently, for input typed by humans or pasted from other documents.
+    ///
+    /// In addition to the aliases and delimiters accepted by [`FromStr`](core::str::FromStr),
+    /// the following characters are folded into the canonical alphabet before decoding:
+    ///
+    /// - Full-width ASCII produced by Japanese and Chinese IMEs (e.g. `１２３ａｂｃｄ`).
+    /// - Cyrillic and Greek letters looking same as Latin letters (e.g. Cyrillic `о`, Greek `Ο`).
+    /// - Whitespace including non-breaking and ideographic spaces, zero-width characters, and dashes like `‐`, `−` and `ー`, which are skipped.
+    ///
+    /// # Error
+    ///
+    /// - [`Error::InvalidCharacter`] with the character index (not the byte position) if a character cannot be folded.
+    /// - [`Error::InvalidLength`] with the number of characters left after skipping, if it is not 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let id: CarettaId = "123abcd".parse()?;
+    ///
+    /// assert_eq!(CarettaId::parse_lenient("１２３ａｂｃｄ")?, id);
+    /// assert_eq!(CarettaId::parse_lenient(" 123\u{a0}аbсd\u{200b}")?, id);
+    /// assert_eq!(CarettaId::parse_lenient("123ーABCD")?, id);
+    ///
+    /// // Length is counted in characters.
+    /// assert_eq!(CarettaId::parse_lenient("１２３ａｂｃ"), Err(Error::InvalidLength(6)));
+    /// assert_eq!(
+    ///     CarettaId::parse_lenient("１２３ａｂｃ！"),
+    ///     Err(Error::InvalidCharacter { character: '！', index: 6 })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, Error> {
+        let mut value = 0;
+        let mut len = 0;
+        for (index, character) in s.chars().enumerate() {
+            if let Folded::Char(folded) = fold(character) {
+                let u5 = char_to_u5(folded).ok_or(Error::InvalidCharacter { character, index })?;
+                value = (value << 5) | u5 as u64;
+                len += 1;
+            }
+        }
+        if len == 7 {
+            Ok(Self::from_u64_lossy(value))
+        } else {
+            Err(Error::InvalidLength(len))
+        }
+    }
+}
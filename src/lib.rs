@@ -54,6 +54,7 @@
 //! - `arbitrary`: `arbitrary::Arbitrary` support for fuzzing tests.
 //! - `serde`: Serialization/deserialization support
 //! - `rusqlite`: SQLite database integration
+//! - `rusqlite-functions`: SQL functions for SQLite, enabling `functions` feature of `rusqlite`
 //! - `sea-orm`: SeaORM ORM integration  
 //! - `prost`: Protocol Buffers support
 //! - `redb`: `redb` integration
@@ -103,13 +104,37 @@ extern crate core as std;
 /// - Characters are separated by hyphens every three characters (triplet) during encoding.
 ///   During decoding, hyphens may be omitted or replaced with underscores.
 pub mod alphabet;
+mod any;
+mod as_text;
+mod canonical;
 mod caretta_id;
+mod checked;
+mod cipher;
 mod double;
+mod encoded;
 mod error;
+#[cfg(feature = "std")]
+mod estimate;
+mod format;
+#[cfg(feature = "std")]
+mod index;
+mod lenient;
+mod lossy;
 mod macros;
+mod migrate;
+mod mnemonic;
+mod name;
+mod node;
+mod phonetic;
 mod quadruple;
+mod scan;
 mod single;
+mod store;
+mod suggest;
+mod time_ordered;
 mod triple;
+mod typed;
+mod variant;
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
@@ -132,16 +157,53 @@ mod rusqlite;
 #[cfg(feature = "sea-orm")]
 mod sea_orm;
 
+#[cfg(feature = "rusqlite-functions")]
+mod sqlite_functions;
+
 /// Provides [`Triplet`](triplet::Triplet) and [`TripletError`](triplet::TripletError).
 #[deprecated(since = "0.8.1")]
 pub mod triplet;
 
+pub use any::AnyCarettaId;
+pub use as_text::AsText;
+#[cfg(feature = "std")]
+pub use canonical::Canonicalization;
 pub use caretta_id::CarettaId;
+pub use checked::CheckedCarettaId;
+pub use cipher::CarettaIdCipher;
 pub use double::CarettaIdD;
+pub use encoded::EncodedCarettaId;
 pub use error::Error;
+#[cfg(feature = "std")]
+pub use estimate::CollisionEstimator;
+pub use format::{CarettaIdDisplay, CarettaIdFormat};
+#[cfg(feature = "std")]
+pub use index::{CarettaIdIndex, ResolveError};
+pub use lossy::LossyCarettaId;
+#[cfg(feature = "std")]
+pub use migrate::CarettaIdRemap;
+pub use migrate::MigrateError;
+#[cfg(feature = "redb")]
+pub use migrate::migrate_redb_table;
+pub use mnemonic::CarettaIdMnemonic;
+pub use node::NodeGenerator;
+pub use phonetic::CarettaIdPhonetic;
 pub use quadruple::CarettaIdQ;
+#[cfg(feature = "redb")]
+pub use redb::UncheckedCarettaId;
+pub use scan::{CarettaIdMatch, CarettaIdMatches, CarettaIdScanner};
 pub use single::CarettaIdS;
+#[cfg(feature = "rusqlite-functions")]
+pub use sqlite_functions::register_sqlite_functions;
+#[cfg(feature = "rusqlite")]
+pub use store::CarettaIdColumn;
+pub use store::{CarettaIdStore, GenerateUniqueError};
+#[cfg(feature = "std")]
+pub use suggest::CarettaIdSuggestion;
+pub use time_ordered::{MonotonicTimeOrderedGenerator, TimeOrderedGenerator};
 pub use triple::CarettaIdT;
+pub use typed::{CarettaIdKind, TypedCarettaId};
+pub use variant::CarettaIdVariant;
 
 /// Provides message types generated by prost-build.
 #[cfg(feature = "prost")]
@@ -0,0 +1,48 @@
+#[cfg(feature = "redb")]
+mod redb;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+
+use crate::CarettaId;
+
+/// Wrapper of [`CarettaId`] which truncates out-of-range values when read from storage.
+///
+/// Storage integrations of [`CarettaId`] (`rusqlite`, `sea-orm` and `redb`) reject stored values larger than [`CarettaId::MAX`],
+/// so that a corrupted or foreign value does not silently become a different id.
+/// `redb` panics on such values because `Value::from_bytes` cannot return an error,
+/// unless the table is opened with [`UncheckedCarettaId`](crate::UncheckedCarettaId).
+/// This type opts in to the truncation of [`CarettaId::from_u64_lossy`] instead.
+/// The stored representation is same as [`CarettaId`].
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "default", feature = "rusqlite"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "default", feature = "rusqlite")), doc = "```ignore")]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn = rusqlite::Connection::open_in_memory()?;
+/// let oversized = CarettaId::MAX.to_u64() + 2;
+///
+/// assert!(conn.query_row("SELECT ?1", [oversized], |row| row.get::<_, CarettaId>(0)).is_err());
+///
+/// let LossyCarettaId(id) = conn.query_row("SELECT ?1", [oversized], |row| row.get(0))?;
+/// assert_eq!(id, CarettaId::from_u64_lossy(oversized));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LossyCarettaId(pub CarettaId);
+
+impl From<CarettaId> for LossyCarettaId {
+    fn from(value: CarettaId) -> Self {
+        Self(value)
+    }
+}
+
+impl From<LossyCarettaId> for CarettaId {
+    fn from(value: LossyCarettaId) -> Self {
+        value.0
+    }
+}
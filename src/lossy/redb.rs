@@ -0,0 +1,32 @@
+use super::LossyCarettaId;
+use crate::CarettaId;
+use ::redb::*;
+
+impl Key for LossyCarettaId {
+    fn compare(data1: &[u8], data2: &[u8]) -> std::cmp::Ordering {
+        <CarettaId as Key>::compare(data1, data2)
+    }
+}
+
+impl Value for LossyCarettaId {
+    type SelfType<'a> = Self;
+    type AsBytes<'a> = <CarettaId as Value>::AsBytes<'a>;
+    fn fixed_width() -> Option<usize> {
+        <CarettaId as Value>::fixed_width()
+    }
+    fn from_bytes<'a>(data: &'a [u8]) -> Self::SelfType<'a>
+    where
+        Self: 'a,
+    {
+        Self(CarettaId::from_u64_lossy(<u64 as Value>::from_bytes(data)))
+    }
+    fn as_bytes<'a, 'b: 'a>(value: &'a Self::SelfType<'b>) -> Self::AsBytes<'a>
+    where
+        Self: 'b,
+    {
+        <CarettaId as Value>::as_bytes(&value.0)
+    }
+    fn type_name() -> TypeName {
+        <CarettaId as Value>::type_name()
+    }
+}
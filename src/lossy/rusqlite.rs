@@ -0,0 +1,16 @@
+use super::LossyCarettaId;
+use crate::CarettaId;
+use rusqlite::{ToSql, types::FromSql};
+
+impl FromSql for LossyCarettaId {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        let int = u64::column_result(value)?;
+        Ok(Self(CarettaId::from_u64_lossy(int)))
+    }
+}
+
+impl ToSql for LossyCarettaId {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
@@ -0,0 +1,48 @@
+use sea_orm::TryFromU64;
+
+use super::LossyCarettaId;
+use crate::CarettaId;
+
+impl From<LossyCarettaId> for sea_orm::Value {
+    fn from(value: LossyCarettaId) -> Self {
+        value.0.into()
+    }
+}
+
+impl sea_orm::TryGetable for LossyCarettaId {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        index: I,
+    ) -> Result<Self, sea_orm::TryGetError> {
+        <u64 as sea_orm::TryGetable>::try_get_by(res, index)
+            .map(|x| Self(CarettaId::from_u64_lossy(x)))
+    }
+}
+
+impl sea_orm::sea_query::ValueType for LossyCarettaId {
+    fn try_from(v: sea_orm::Value) -> Result<Self, sea_orm::sea_query::ValueTypeErr> {
+        <u64 as sea_orm::sea_query::ValueType>::try_from(v)
+            .map(|x| Self(CarettaId::from_u64_lossy(x)))
+    }
+    fn type_name() -> String {
+        stringify!(LossyCarettaId).to_owned()
+    }
+    fn array_type() -> sea_orm::sea_query::ArrayType {
+        <CarettaId as sea_orm::sea_query::ValueType>::array_type()
+    }
+    fn column_type() -> sea_orm::ColumnType {
+        <CarettaId as sea_orm::sea_query::ValueType>::column_type()
+    }
+}
+
+impl sea_orm::sea_query::Nullable for LossyCarettaId {
+    fn null() -> sea_orm::Value {
+        <CarettaId as sea_orm::sea_query::Nullable>::null()
+    }
+}
+
+impl TryFromU64 for LossyCarettaId {
+    fn try_from_u64(n: u64) -> Result<Self, sea_orm::DbErr> {
+        Ok(Self(CarettaId::from_u64_lossy(n)))
+    }
+}
@@ -255,6 +255,29 @@ macro_rules! caretta_id_impl {
             }
         }
 
+        #[allow(deprecated)]
+        impl crate::CarettaIdVariant for $SelfT {
+            type Uint = $Uint;
+
+            const BITS: u32 = $BITS;
+
+            const NIL: Self = <$SelfT>::NIL;
+
+            const MAX: Self = <$SelfT>::MAX;
+
+            fn from_uint(uint: Self::Uint) -> Result<Self, Error> {
+                <$SelfT>::from_uint(uint)
+            }
+
+            fn from_uint_lossy(uint: Self::Uint) -> Self {
+                <$SelfT>::from_uint_lossy(uint)
+            }
+
+            fn to_uint(self) -> Self::Uint {
+                <$SelfT>::to_uint(self)
+            }
+        }
+
         #[cfg(feature = "arbitrary")]
         mod arbitrary {
             use ::arbitrary::{Arbitrary, Unstructured, Result};
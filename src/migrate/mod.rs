@@ -0,0 +1,275 @@
+#[cfg(feature = "redb")]
+mod redb;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
+#[cfg(feature = "redb")]
+pub use redb::migrate_redb_table;
+
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet, btree_map};
+
+use crate::{CarettaId, CarettaIdVariant, Error, variant::variant_from_u64};
+#[allow(deprecated)]
+use crate::{CarettaIdD, CarettaIdQ, CarettaIdS, CarettaIdT};
+
+impl CarettaId {
+    /// Attempts to convert any [`CarettaIdVariant`], e.g. a legacy [`CarettaIdT`], to [`CarettaId`] keeping its value.
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::ValueOutOfRange`] if the value is larger than [`CarettaId::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let legacy = CarettaIdT::from_uint(0x123456789)?;
+    /// assert_eq!(CarettaId::try_from_variant(legacy)?, CarettaId::from_u64(0x123456789)?);
+    /// assert!(CarettaId::try_from_variant(CarettaIdT::MAX).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_variant<T: CarettaIdVariant>(id: T) -> Result<Self, Error> {
+        Self::from_u64(id.to_uint().into())
+    }
+
+    /// Converts any [`CarettaIdVariant`] to [`CarettaId`] by truncating bits that exceed the valid range.
+    ///
+    /// Different legacy ids may be converted to the same [`CarettaId`].
+    /// Use [`CarettaIdRemap`] to convert a batch of ids without collisions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let legacy = CarettaIdQ::from_uint(CarettaId::MAX.to_u64() + 2)?;
+    /// assert_eq!(CarettaId::from_variant_lossy(legacy), CarettaId::from_u64(1)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_variant_lossy<T: CarettaIdVariant>(id: T) -> Self {
+        Self::from_u64_lossy(id.to_uint().into())
+    }
+
+    /// Attempts to convert to any [`CarettaIdVariant`] keeping its value.
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::ValueOutOfRange`] if the value is larger than `T::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let id = CarettaId::from_u64(0x7fff)?;
+    /// assert_eq!(id.try_into_variant::<CarettaIdS>()?, CarettaIdS::MAX);
+    /// assert!(CarettaId::MAX.try_into_variant::<CarettaIdS>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_into_variant<T: CarettaIdVariant>(self) -> Result<T, Error> {
+        variant_from_u64(self.to_u64())
+    }
+
+    /// Converts to any [`CarettaIdVariant`] by truncating bits that exceed the range of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use caretta_id::*;
+    /// assert_eq!(CarettaId::MAX.into_variant_lossy::<CarettaIdS>(), CarettaIdS::MAX);
+    /// assert_eq!(CarettaId::MAX.into_variant_lossy::<CarettaIdT>().to_uint(), CarettaId::MAX.to_u64());
+    /// ```
+    pub fn into_variant_lossy<T: CarettaIdVariant>(self) -> T {
+        let masked = self.to_u64() & T::MAX.to_uint().into();
+        T::Uint::try_from(masked).map_or(T::NIL, T::from_uint_lossy)
+    }
+}
+
+macro_rules! impl_from_legacy {
+    ($($SelfT:ty),*) => {
+        $(
+            #[allow(deprecated)]
+            impl From<$SelfT> for CarettaId {
+                fn from(value: $SelfT) -> Self {
+                    Self::from_u64_lossy(value.to_uint().into())
+                }
+            }
+
+            #[allow(deprecated)]
+            impl TryFrom<CarettaId> for $SelfT {
+                type Error = Error;
+
+                fn try_from(value: CarettaId) -> Result<Self, Self::Error> {
+                    value.try_into_variant()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_try_from_legacy {
+    ($($SelfT:ty),*) => {
+        $(
+            #[allow(deprecated)]
+            impl TryFrom<$SelfT> for CarettaId {
+                type Error = Error;
+
+                fn try_from(value: $SelfT) -> Result<Self, Self::Error> {
+                    Self::try_from_variant(value)
+                }
+            }
+
+            #[allow(deprecated)]
+            impl From<CarettaId> for $SelfT {
+                fn from(value: CarettaId) -> Self {
+                    value.into_variant_lossy()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_legacy!(CarettaIdS, CarettaIdD);
+impl_try_from_legacy!(CarettaIdT, CarettaIdQ);
+
+/// Mapping table from legacy ids to [`CarettaId`]s, built from a batch of ids without collisions.
+///
+/// Ids are converted in ascending order.
+/// Ids within the range of [`CarettaId`] keep their value and the others are truncated like [`CarettaId::from_variant_lossy`].
+/// If the truncated id is already assigned, the id is detected as a collision
+/// and reassigned to the next unassigned value, wrapping around at [`CarettaId::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(deprecated)]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let old = [
+///     CarettaIdT::from_uint(1)?,
+///     CarettaIdT::from_uint(CarettaId::MAX.to_u64() + 2)?,
+///     CarettaIdT::from_uint(CarettaId::MAX.to_u64() + 3)?,
+/// ];
+/// let remap = CarettaIdRemap::new(old);
+///
+/// assert_eq!(remap.get(&old[0]), Some(CarettaId::from_u64(1)?));
+/// assert_eq!(remap.get(&old[1]), Some(CarettaId::from_u64(2)?));
+/// assert_eq!(remap.get(&old[2]), Some(CarettaId::from_u64(3)?));
+/// assert_eq!(remap.collisions(), &old[1..]);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CarettaIdRemap<T> {
+    mapping: BTreeMap<T, CarettaId>,
+    collisions: Vec<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T: CarettaIdVariant> CarettaIdRemap<T> {
+    /// Builds the mapping table from the ids. Duplicated ids are ignored.
+    pub fn new<I: IntoIterator<Item = T>>(ids: I) -> Self {
+        let ids: BTreeSet<T> = ids.into_iter().collect();
+        let mut assigned: BTreeSet<CarettaId> = ids
+            .iter()
+            .filter_map(|id| CarettaId::try_from_variant(*id).ok())
+            .collect();
+        let mut mapping = BTreeMap::new();
+        let mut collisions = Vec::new();
+        for id in ids {
+            let new = match CarettaId::try_from_variant(id) {
+                Ok(new) => new,
+                Err(_) => {
+                    let mut new = CarettaId::from_variant_lossy(id);
+                    if assigned.contains(&new) {
+                        collisions.push(id);
+                        while assigned.contains(&new) {
+                            new = CarettaId::from_u64_lossy(new.to_u64() + 1);
+                        }
+                    }
+                    assigned.insert(new);
+                    new
+                }
+            };
+            mapping.insert(id, new);
+        }
+        Self {
+            mapping,
+            collisions,
+        }
+    }
+
+    /// Returns the new id of the legacy id.
+    pub fn get(&self, id: &T) -> Option<CarettaId> {
+        self.mapping.get(id).copied()
+    }
+
+    /// Returns legacy ids reassigned because their truncated id was already assigned, in ascending order.
+    pub fn collisions(&self) -> &[T] {
+        &self.collisions
+    }
+
+    /// Returns `true` if every id keeps its value.
+    pub fn is_identity(&self) -> bool {
+        self.iter()
+            .all(|(old, new)| old.to_uint().into() == new.to_u64())
+    }
+
+    /// Returns the number of ids.
+    pub fn len(&self) -> usize {
+        self.mapping.len()
+    }
+
+    /// Returns `true` if there is no id.
+    pub fn is_empty(&self) -> bool {
+        self.mapping.is_empty()
+    }
+
+    /// Returns an iterator over pairs of legacy and new ids, in ascending order of legacy ids.
+    pub fn iter(&self) -> btree_map::Iter<'_, T, CarettaId> {
+        self.mapping.iter()
+    }
+
+    /// Returns the mapping table.
+    pub fn into_map(self) -> BTreeMap<T, CarettaId> {
+        self.mapping
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: CarettaIdVariant> FromIterator<T> for CarettaIdRemap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T> IntoIterator for &'a CarettaIdRemap<T> {
+    type Item = (&'a T, &'a CarettaId);
+    type IntoIter = btree_map::Iter<'a, T, CarettaId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.mapping.iter()
+    }
+}
+
+/// An error that can occur when migrating stored legacy ids to [`CarettaId`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum MigrateError<E> {
+    /// A stored value is not a valid id of the legacy variant, e.g. larger than its `MAX`.
+    #[error("Invalid legacy id: {0}")]
+    Id(Error),
+    /// The underlying store, e.g. SQLite or `redb`, failed.
+    #[error("Store error: {0}")]
+    Store(E),
+}
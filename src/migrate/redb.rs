@@ -0,0 +1,92 @@
+use redb::{Key, ReadableTable, TableDefinition, TableError, TableHandle, Value, WriteTransaction};
+
+use super::{CarettaIdRemap, MigrateError};
+use crate::{CarettaId, CarettaIdVariant};
+
+/// Rewrites a redb table keyed by legacy ids to a table keyed by [`CarettaId`] with the same name,
+/// and returns the mapping table.
+///
+/// The keys are remapped by [`CarettaIdRemap`] and the values are kept as is.
+/// Changes are made only within the transaction, so nothing is written unless it is committed.
+///
+/// # Error
+///
+/// Returns [`MigrateError::Store`] with [`TableError::TableDoesNotExist`] if the table does not exist,
+/// or other errors if the table is not keyed by `T` or accessing the database failed.
+///
+/// # Panics
+///
+/// Values are decoded and encoded again by `V`, so this panics if `V` panics on a stored value,
+/// e.g. [`CarettaId`] or [`TypedCarettaId`](crate::TypedCarettaId) larger than [`CarettaId::MAX`].
+/// Use [`UncheckedCarettaId`](crate::UncheckedCarettaId) as `V` to migrate such values as is.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(deprecated)]
+/// # use caretta_id::*;
+/// # use redb::{Database, ReadableDatabase, TableDefinition, backends::InMemoryBackend};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// const LEGACY: TableDefinition<CarettaIdT, &str> = TableDefinition::new("note");
+/// const TABLE: TableDefinition<CarettaId, &str> = TableDefinition::new("note");
+///
+/// let database = Database::builder().create_with_backend(InMemoryBackend::new())?;
+/// let legacy = CarettaIdT::from_uint(CarettaId::MAX.to_u64() + 2)?;
+/// let write_txn = database.begin_write()?;
+/// write_txn.open_table(LEGACY)?.insert(legacy, "hello")?;
+/// write_txn.commit()?;
+///
+/// let write_txn = database.begin_write()?;
+/// let remap = migrate_redb_table::<CarettaIdT, &str>(&write_txn, "note")?;
+/// write_txn.commit()?;
+///
+/// let read_txn = database.begin_read()?;
+/// let table = read_txn.open_table(TABLE)?;
+/// assert_eq!(table.get(remap.get(&legacy).unwrap())?.unwrap().value(), "hello");
+/// # Ok(())
+/// # }
+/// ```
+pub fn migrate_redb_table<T, V>(
+    txn: &WriteTransaction,
+    name: &str,
+) -> Result<CarettaIdRemap<T>, MigrateError<redb::Error>>
+where
+    T: CarettaIdVariant + Key + for<'a> Value<SelfType<'a> = T>,
+    V: Value + 'static,
+{
+    // `open_table` creates a missing table, so check it first not to migrate a misspelled table silently.
+    let exists = txn
+        .list_tables()
+        .map_err(|e| MigrateError::Store(e.into()))?
+        .any(|x| x.name() == name);
+    if !exists {
+        return Err(MigrateError::Store(
+            TableError::TableDoesNotExist(name.to_owned()).into(),
+        ));
+    }
+    let legacy: TableDefinition<T, V> = TableDefinition::new(name);
+    let mut entries = Vec::new();
+    {
+        let table = txn
+            .open_table(legacy)
+            .map_err(|e| MigrateError::Store(e.into()))?;
+        for entry in table.iter().map_err(|e| MigrateError::Store(e.into()))? {
+            let (key, value) = entry.map_err(|e| MigrateError::Store(e.into()))?;
+            entries.push((key.value(), V::as_bytes(&value.value()).as_ref().to_vec()));
+        }
+    }
+    txn.delete_table(legacy)
+        .map_err(|e| MigrateError::Store(e.into()))?;
+
+    let remap = CarettaIdRemap::new(entries.iter().map(|(id, _)| *id));
+    let mut table = txn
+        .open_table(TableDefinition::<CarettaId, V>::new(name))
+        .map_err(|e| MigrateError::Store(e.into()))?;
+    for (id, value) in &entries {
+        let new = remap.get(id).expect("every legacy id is remapped");
+        table
+            .insert(new, V::from_bytes(value))
+            .map_err(|e| MigrateError::Store(e.into()))?;
+    }
+    Ok(remap)
+}
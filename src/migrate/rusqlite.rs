@@ -0,0 +1,85 @@
+use super::{CarettaIdRemap, MigrateError};
+use crate::{CarettaIdColumn, CarettaIdVariant, store::quote, variant::variant_from_u64};
+
+impl CarettaIdColumn<'_> {
+    /// Rewrites legacy ids stored in the column to [`CarettaId`](crate::CarettaId)s in place, and returns the mapping table.
+    ///
+    /// The ids are remapped by [`CarettaIdRemap`] and updated within a savepoint,
+    /// so the column is left untouched on error. This also works inside an outer transaction.
+    ///
+    /// # Error
+    ///
+    /// - [`MigrateError::Id`] if the column contains a value larger than `T::MAX`.
+    /// - [`MigrateError::Store`] if the query failed.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "default", doc = "```rust")]
+    #[cfg_attr(not(feature = "default"), doc = "```ignore")]
+    /// # #![allow(deprecated)]
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let conn = rusqlite::Connection::open_in_memory()?;
+    /// conn.execute("CREATE TABLE note (id INTEGER PRIMARY KEY, body TEXT)", ())?;
+    /// let legacy = CarettaIdT::from_uint(CarettaId::MAX.to_u64() + 2)?;
+    /// conn.execute("INSERT INTO note (id, body) VALUES (?1, 'hello')", [legacy])?;
+    ///
+    /// let remap = CarettaIdColumn::new(&conn, "note", "id").migrate::<CarettaIdT>()?;
+    /// let id: CarettaId = conn.query_row("SELECT id FROM note", (), |row| row.get(0))?;
+    /// assert_eq!(remap.get(&legacy), Some(id));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn migrate<T: CarettaIdVariant>(
+        &self,
+    ) -> Result<CarettaIdRemap<T>, MigrateError<rusqlite::Error>> {
+        self.conn
+            .execute_batch("SAVEPOINT caretta_id_migrate")
+            .map_err(MigrateError::Store)?;
+        let result = self.migrate_within_savepoint();
+        let end = if result.is_ok() {
+            "RELEASE caretta_id_migrate"
+        } else {
+            "ROLLBACK TO caretta_id_migrate; RELEASE caretta_id_migrate"
+        };
+        self.conn.execute_batch(end).map_err(MigrateError::Store)?;
+        result
+    }
+
+    fn migrate_within_savepoint<T: CarettaIdVariant>(
+        &self,
+    ) -> Result<CarettaIdRemap<T>, MigrateError<rusqlite::Error>> {
+        let table = quote(self.table);
+        let column = quote(self.column);
+        let mut select = self
+            .conn
+            .prepare(&format!(
+                "SELECT DISTINCT {column} FROM {table} WHERE {column} IS NOT NULL"
+            ))
+            .map_err(MigrateError::Store)?;
+        let ids = select
+            .query_map((), |row| row.get::<_, u64>(0))
+            .map_err(MigrateError::Store)?
+            .map(|x| {
+                x.map_err(MigrateError::Store)
+                    .and_then(|x| variant_from_u64::<T>(x).map_err(MigrateError::Id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let remap = CarettaIdRemap::new(ids);
+        let mut update = self
+            .conn
+            .prepare(&format!(
+                "UPDATE {table} SET {column} = ?1 WHERE {column} = ?2"
+            ))
+            .map_err(MigrateError::Store)?;
+        for (old, new) in &remap {
+            let old: u64 = old.to_uint().into();
+            if old != new.to_u64() {
+                update
+                    .execute(rusqlite::params![new, old])
+                    .map_err(MigrateError::Store)?;
+            }
+        }
+        Ok(remap)
+    }
+}
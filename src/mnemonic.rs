@@ -0,0 +1,215 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{CarettaId, Error};
+
+/// Reversible representation of [`CarettaId`] as 4 words, for speaking ids aloud (e.g. `airport-kidney-diamond-raccoon`).
+///
+/// The 35 bits are split into 8, 9, 9 and 9 bits from the most significant bit,
+/// and each part is the index into [`WORDS`](Self::WORDS), a fixed list of 512 common English nouns.
+/// Every pair of words in the list differs by at least 3 edits,
+/// so that a single misspelling per word can be corrected unambiguously.
+/// See [SPECS.md](https://github.com/fluo10/caretta-id/blob/main/SPECS.md) for details.
+///
+/// Parsing is case-insensitive, accepts whitespace, `-`, `_`, `,` and `.` as separators,
+/// and corrects a single inserted, deleted or substituted letter per word.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let id: CarettaId = "123abcd".parse()?;
+/// let mnemonic = id.to_mnemonic();
+/// assert_eq!(mnemonic.to_string(), "airport-kidney-diamond-raccoon");
+/// assert_eq!(mnemonic.words(), ["airport", "kidney", "diamond", "raccoon"]);
+///
+/// // Case and misspellings are tolerated.
+/// assert_eq!(CarettaId::from_mnemonic("Airport Kidny Diamond Racoon")?, id);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CarettaIdMnemonic(CarettaId);
+
+impl CarettaIdMnemonic {
+    /// Number of words in a mnemonic.
+    pub const LEN: usize = 4;
+
+    /// The word list, sorted alphabetically.
+    pub const WORDS: &[&str; 512] = &[
+        "abbey", "acid", "acorn", "admiral", "adobe", "aerial", "agate", "agent", "airport",
+        "airship", "alarm", "alien", "alloy", "almond", "alpha", "anchor", "angel", "ankle",
+        "anthem", "antique", "apex", "apricot", "apron", "arch", "armor", "artist", "atlas",
+        "atom", "attic", "aura", "autumn", "avenue", "aviator", "avocado", "baby", "badge",
+        "baker", "ballet", "balloon", "bamboo", "banana", "bandit", "banjo", "banquet", "barrel",
+        "battery", "bazaar", "beach", "beef", "beehive", "beetle", "belfry", "bicycle", "bird",
+        "biscuit", "blanket", "blaze", "blender", "blimp", "blossom", "boat", "bonnet", "bonus",
+        "border", "brain", "bread", "breeze", "brick", "broom", "brush", "bubble", "bucket",
+        "buffalo", "bulb", "bulldog", "bunny", "burrito", "burrow", "butler", "button", "cabin",
+        "cactus", "cafe", "camera", "canal", "captain", "caramel", "cargo", "carpet", "cartoon",
+        "cashew", "castle", "catfish", "caviar", "cedar", "cell", "chair", "chapel", "chariot",
+        "cheetah", "cherry", "chess", "chicken", "chili", "chimney", "chorus", "cinema", "circle",
+        "city", "cliff", "clock", "clover", "cobalt", "cobra", "cockpit", "coconut", "coffee",
+        "collar", "comb", "compass", "concert", "condo", "cookie", "copper", "cosmos", "couch",
+        "coupon", "cowboy", "coyote", "cradle", "crater", "crayon", "crimson", "crown", "crystal",
+        "cuckoo", "cup", "cupcake", "cushion", "cymbal", "cypress", "dahlia", "dairy", "dance",
+        "decoy", "delta", "denim", "desert", "desk", "dial", "diamond", "diesel", "dimple",
+        "dinghy", "dinner", "doctor", "dolphin", "domain", "donkey", "drama", "dynamo", "eagle",
+        "earring", "easel", "ebony", "echo", "eclipse", "egg", "elbow", "elder", "element",
+        "elixir", "emerald", "empire", "engine", "essay", "estate", "exit", "eyebrow", "fabric",
+        "fajita", "falcon", "family", "feast", "feather", "fennel", "fern", "ferret", "fever",
+        "fiddle", "field", "fireman", "fish", "fjord", "flag", "flint", "flurry", "flute",
+        "forest", "fossil", "fox", "fresco", "fruit", "furnace", "galaxy", "gallery", "garden",
+        "garlic", "gasket", "gazebo", "gazelle", "gem", "geyser", "ghost", "giant", "giraffe",
+        "glacier", "glass", "glitter", "goblet", "gold", "gondola", "gorilla", "granite", "grape",
+        "griffin", "grizzly", "grotto", "guitar", "gumbo", "haiku", "halo", "hammer", "hammock",
+        "handbag", "harvest", "helium", "helmet", "highway", "hippo", "hobbit", "hook", "horizon",
+        "horse", "hotel", "hunter", "husky", "hyena", "igloo", "iguana", "index", "insect", "iris",
+        "island", "ivory", "jackal", "jaguar", "jar", "jasmine", "jeans", "jersey", "jester",
+        "jewel", "jigsaw", "juice", "jukebox", "jungle", "juniper", "kayak", "kidney", "kilt",
+        "kimono", "kingdom", "kitten", "knee", "knight", "koala", "ladder", "lagoon", "lantern",
+        "laptop", "latte", "laundry", "lava", "legend", "lemon", "leopard", "lettuce", "library",
+        "lilac", "lizard", "lobby", "lobster", "lullaby", "lumber", "lunar", "lynx", "macaw",
+        "magnet", "magpie", "mailbox", "mammoth", "manatee", "mansion", "mantis", "marble",
+        "mascot", "matador", "mayor", "meadow", "menu", "mercury", "mermaid", "metal", "meteor",
+        "mimosa", "minnow", "mirror", "mobile", "mocha", "moped", "mosaic", "moss", "muffin",
+        "museum", "mussel", "mustard", "nail", "napkin", "narwhal", "nebula", "nectar", "nickel",
+        "nomad", "noodle", "north", "nugget", "nutmeg", "oasis", "oatmeal", "oboe", "ocean",
+        "ocelot", "octopus", "office", "olive", "onion", "onyx", "opera", "orange", "orchid",
+        "osprey", "ostrich", "otter", "owl", "pagoda", "pajamas", "palace", "pancake", "parka",
+        "parrot", "parsley", "pastry", "patio", "peacock", "peanut", "pecan", "pencil", "penguin",
+        "pickle", "pigeon", "piglet", "pillow", "pinball", "pine", "pirate", "pizza", "plum",
+        "podium", "poncho", "popcorn", "poppy", "potato", "prairie", "pretzel", "pudding",
+        "pulley", "pumpkin", "puppet", "puzzle", "pyramid", "python", "quarry", "quasar", "queen",
+        "quill", "quiver", "raccoon", "radish", "raisin", "rapids", "raven", "recipe", "record",
+        "relay", "reptile", "rhino", "ribbon", "rodeo", "rowboat", "safari", "salad", "saturn",
+        "sausage", "scarf", "school", "scooter", "season", "sheep", "shrimp", "sierra", "silver",
+        "siren", "ski", "skyline", "slipper", "sloth", "snake", "snorkel", "snow", "soccer",
+        "socks", "sofa", "sphinx", "spider", "spinach", "sponge", "sprout", "spruce", "squash",
+        "squid", "stable", "stadium", "stamp", "stork", "straw", "studio", "summit", "sushi",
+        "swan", "sweater", "syrup", "tadpole", "tailor", "tank", "tapir", "teacup", "teapot",
+        "tennis", "terrace", "thimble", "throne", "thyme", "tiara", "tiger", "tonic", "toolbox",
+        "topaz", "tornado", "toucan", "tractor", "tribe", "trolley", "trophy", "truffle",
+        "trumpet", "tugboat", "tulip", "tundra", "turban", "turkey", "turtle", "tuxedo", "twig",
+        "ukulele", "unicorn", "vanilla", "vendor", "village", "violin", "visor", "vitamin",
+        "vocal", "volcano", "vortex", "voyage", "waffle", "waiter", "walnut", "wasp", "wheat",
+        "wigwam", "wombat", "wrench", "yacht", "yeti", "yogurt", "yoyo", "zenith", "zephyr",
+        "zinnia", "zodiac",
+    ];
+
+    /// Creates a new mnemonic of the id.
+    pub const fn new(id: CarettaId) -> Self {
+        Self(id)
+    }
+
+    /// Returns the inner [`CarettaId`].
+    pub const fn id(self) -> CarettaId {
+        self.0
+    }
+
+    /// Returns the words of the mnemonic.
+    pub const fn words(self) -> [&'static str; Self::LEN] {
+        let value = self.0.to_u64();
+        [
+            Self::WORDS[(value >> 27) as usize],
+            Self::WORDS[(value >> 18) as usize & 0x1ff],
+            Self::WORDS[(value >> 9) as usize & 0x1ff],
+            Self::WORDS[value as usize & 0x1ff],
+        ]
+    }
+}
+
+impl CarettaId {
+    /// Converts to a [`CarettaIdMnemonic`].
+    pub const fn to_mnemonic(self) -> CarettaIdMnemonic {
+        CarettaIdMnemonic::new(self)
+    }
+
+    /// Parses the words of [`CarettaIdMnemonic`] to [`CarettaId`].
+    ///
+    /// # Error
+    ///
+    /// - [`Error::InvalidWordCount`] if the number of words is not 4.
+    /// - [`Error::UnknownWord`] with the index of the word if it is not in the list even after correcting a single misspelling.
+    /// - [`Error::ValueOutOfRange`] if the first word is out of the first 256 words.
+    pub fn from_mnemonic(s: &str) -> Result<Self, Error> {
+        s.parse::<CarettaIdMnemonic>().map(CarettaIdMnemonic::id)
+    }
+}
+
+/// Find the index of the word, correcting a single misspelling.
+fn find_word(word: &str) -> Option<usize> {
+    let word = word.as_bytes();
+    CarettaIdMnemonic::WORDS
+        .iter()
+        .position(|x| x.as_bytes().eq_ignore_ascii_case(word))
+        .or_else(|| {
+            CarettaIdMnemonic::WORDS
+                .iter()
+                .position(|x| is_single_edit(x.as_bytes(), word))
+        })
+}
+
+/// Test if `input` is `word` with a single inserted, deleted or substituted letter, ignoring ASCII case.
+fn is_single_edit(word: &[u8], input: &[u8]) -> bool {
+    let (shorter, longer) = if word.len() <= input.len() {
+        (word, input)
+    } else {
+        (input, word)
+    };
+    if longer.len() - shorter.len() > 1 {
+        return false;
+    }
+    let prefix = shorter
+        .iter()
+        .zip(longer)
+        .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+        .count();
+    let skip = if shorter.len() == longer.len() { 1 } else { 0 };
+    shorter
+        .get(prefix + skip..)
+        .unwrap_or_default()
+        .eq_ignore_ascii_case(&longer[(prefix + 1).min(longer.len())..])
+}
+
+impl FromStr for CarettaIdMnemonic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value = 0;
+        let mut count = 0;
+        for word in s
+            .split(|c: char| c.is_whitespace() || matches!(c, '-' | '_' | ',' | '.'))
+            .filter(|x| !x.is_empty())
+        {
+            if count < Self::LEN {
+                let index = find_word(word).ok_or(Error::UnknownWord { index: count })?;
+                value = (value << 9) | index as u64;
+            }
+            count += 1;
+        }
+        if count != Self::LEN {
+            return Err(Error::InvalidWordCount(count));
+        }
+        CarettaId::from_u64(value).map(Self)
+    }
+}
+
+impl Display for CarettaIdMnemonic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [a, b, c, d] = self.words();
+        write!(f, "{}-{}-{}-{}", a, b, c, d)
+    }
+}
+
+impl From<CarettaId> for CarettaIdMnemonic {
+    fn from(value: CarettaId) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<CarettaIdMnemonic> for CarettaId {
+    fn from(value: CarettaIdMnemonic) -> Self {
+        value.id()
+    }
+}
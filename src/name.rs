@@ -0,0 +1,61 @@
+use crate::CarettaId;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+impl CarettaId {
+    /// Derives a deterministic [`CarettaId`] from the namespace and the name, like UUID version 5.
+    ///
+    /// The same pair of namespace and name always results in the same id on every device and platform,
+    /// so that ids of external resources such as file paths or URLs can be shared without coordination.
+    ///
+    /// The id is the upper 35 bits of the 64-bit FNV-1a hash over the namespace and the name, followed by the MurmurHash3 finalizer.
+    /// The algorithm is pinned in [SPECS.md](https://github.com/fluo10/caretta-id/blob/main/SPECS.md) and never changes in future versions.
+    ///
+    /// This is not a cryptographic hash, so the name should not be secret.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let namespace: CarettaId = "123abcd".parse()?;
+    ///
+    /// let id = CarettaId::from_name(namespace, b"example.com");
+    /// assert_eq!(id.to_string(), "f57qjqx");
+    /// assert_eq!(id, CarettaId::from_name(namespace, b"example.com"));
+    ///
+    /// // Different namespace results in different id.
+    /// assert_ne!(id, CarettaId::from_name(CarettaId::NIL, b"example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn from_name(namespace: CarettaId, name: &[u8]) -> Self {
+        let mut hash = FNV_OFFSET_BASIS;
+        let namespace = namespace.to_u64().to_be_bytes();
+        let mut i = 0;
+        while i < namespace.len() {
+            hash = (hash ^ namespace[i] as u64).wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        let mut i = 0;
+        while i < name.len() {
+            hash = (hash ^ name[i] as u64).wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        Self::from_u64_lossy(finalize(hash) >> (u64::BITS - Self::BITS))
+    }
+}
+
+/// 64-bit finalizer of MurmurHash3, as pinned in the "Name-based ID" section of SPECS.md.
+///
+/// This is intentionally separate from the round function of [`CarettaIdCipher`](crate::CarettaIdCipher),
+/// so that changes to the cipher never change name-based ids.
+const fn finalize(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^= hash >> 33;
+    hash
+}
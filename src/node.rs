@@ -0,0 +1,161 @@
+use crate::{CarettaId, Error};
+
+/// Generator of [`CarettaId`] partitioned by node, guaranteeing uniqueness across known devices.
+///
+/// The upper `node_bits` bits of the 35 bits hold the node id of the device,
+/// and the remaining lower bits hold either a counter or random value.
+/// As long as every device has a distinct node id, ids generated by different devices never collide.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// // Up to 16 devices, each with 31 bits of local space.
+/// let mut generator = NodeGenerator::new(4, 3)?.require_local_bits(24)?;
+///
+/// let first = generator.next_counter()?;
+/// let second = generator.next_counter()?;
+/// assert_eq!(generator.node_of(first), 3);
+/// assert_eq!(generator.local_of(first), 0);
+/// assert_eq!(generator.local_of(second), 1);
+///
+/// // Other device never generates the same id.
+/// let other = NodeGenerator::new(4, 5)?.from_local(0);
+/// assert_ne!(first, other);
+/// assert_eq!(generator.node_of(other), 5);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct NodeGenerator {
+    node_bits: u32,
+    node: u64,
+    counter: u64,
+}
+
+impl NodeGenerator {
+    /// Creates a new generator for the node.
+    ///
+    /// # Error
+    ///
+    /// - [`Error::InvalidBitWidth`] unless `node_bits` is between 1 and 34, so that at least 1 bit is left for local value.
+    /// - [`Error::ValueOutOfRange`] if the node id does not fit within `node_bits`.
+    pub const fn new(node_bits: u32, node: u64) -> Result<Self, Error> {
+        if node_bits == 0 || node_bits >= CarettaId::BITS {
+            return Err(Error::InvalidBitWidth(node_bits));
+        }
+        if node >> node_bits != 0 {
+            return Err(Error::ValueOutOfRange(node));
+        }
+        Ok(Self {
+            node_bits,
+            node,
+            counter: 0,
+        })
+    }
+
+    /// Validates that the configuration leaves at least `min_local_bits` bits for local value.
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::InsufficientLocalBits`] with the required and available local bits if the local bits are not enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// assert!(NodeGenerator::new(8, 0).unwrap().require_local_bits(27).is_ok());
+    /// assert_eq!(
+    ///     NodeGenerator::new(8, 0).unwrap().require_local_bits(28),
+    ///     Err(Error::InsufficientLocalBits {
+    ///         required: 28,
+    ///         available: 27
+    ///     })
+    /// );
+    /// ```
+    pub const fn require_local_bits(self, min_local_bits: u32) -> Result<Self, Error> {
+        if self.local_bits() < min_local_bits {
+            Err(Error::InsufficientLocalBits {
+                required: min_local_bits,
+                available: self.local_bits(),
+            })
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Sets the next value of the counter, e.g. to resume from the persisted state after restart.
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::ValueOutOfRange`] if the counter does not fit within the local bits.
+    pub const fn with_counter(mut self, counter: u64) -> Result<Self, Error> {
+        if counter > self.local_mask() {
+            return Err(Error::ValueOutOfRange(counter));
+        }
+        self.counter = counter;
+        Ok(self)
+    }
+
+    /// Returns the number of upper bits used for the node id.
+    pub const fn node_bits(&self) -> u32 {
+        self.node_bits
+    }
+
+    /// Returns the number of lower bits used for the local value.
+    pub const fn local_bits(&self) -> u32 {
+        CarettaId::BITS - self.node_bits
+    }
+
+    /// Returns the node id of this generator.
+    pub const fn node(&self) -> u64 {
+        self.node
+    }
+
+    /// Returns the next value of the counter.
+    pub const fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    const fn local_mask(&self) -> u64 {
+        (1 << self.local_bits()) - 1
+    }
+
+    /// Creates a [`CarettaId`] of this node from the local value.
+    ///
+    /// Bits of `local` exceeding [`local_bits`](Self::local_bits) are truncated.
+    pub const fn from_local(&self, local: u64) -> CarettaId {
+        CarettaId::from_u64_lossy((self.node << self.local_bits()) | (local & self.local_mask()))
+    }
+
+    /// Returns the node id part of the id.
+    pub const fn node_of(&self, id: CarettaId) -> u64 {
+        id.to_u64() >> self.local_bits()
+    }
+
+    /// Returns the local value part of the id.
+    pub const fn local_of(&self, id: CarettaId) -> u64 {
+        id.to_u64() & self.local_mask()
+    }
+
+    /// Generates a new [`CarettaId`] of this node from the counter and increments it.
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::CounterOverflow`] if the local space of this node is exhausted.
+    pub const fn next_counter(&mut self) -> Result<CarettaId, Error> {
+        if self.counter > self.local_mask() {
+            return Err(Error::CounterOverflow);
+        }
+        let id = self.from_local(self.counter);
+        self.counter += 1;
+        Ok(id)
+    }
+
+    /// Generates a new [`CarettaId`] of this node from random value.
+    #[cfg(feature = "rand")]
+    pub fn random(&self) -> CarettaId {
+        self.from_local(::rand::random())
+    }
+}
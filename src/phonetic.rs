@@ -0,0 +1,193 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{CarettaId, Error, alphabet::char_to_u5};
+
+/// Spoken words of the 32 characters of the encoding alphabet, in the order of [`ENCODE_TABLE`](crate::alphabet::ENCODE_TABLE).
+const SPOKEN: [&str; 32] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "alfa",
+    "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "juliett", "kilo", "mike",
+    "november", "papa", "quebec", "romeo", "sierra", "tango", "victor", "whiskey", "x-ray",
+    "yankee", "zulu",
+];
+
+/// Spoken words accepted when parsing, paired with the character they stand for.
+///
+/// Letters are decoded through [`DECODE_TABLE`](crate::alphabet::DECODE_TABLE), so `oscar`, `lima`, `india` and `uniform`
+/// are accepted as `0`, `1`, `1` and `v`.
+const WORDS: &[(&str, char)] = &[
+    ("zero", '0'),
+    ("one", '1'),
+    ("two", '2'),
+    ("three", '3'),
+    ("tree", '3'),
+    ("four", '4'),
+    ("five", '5'),
+    ("fife", '5'),
+    ("six", '6'),
+    ("seven", '7'),
+    ("eight", '8'),
+    ("nine", '9'),
+    ("niner", '9'),
+    ("alfa", 'a'),
+    ("alpha", 'a'),
+    ("bravo", 'b'),
+    ("charlie", 'c'),
+    ("delta", 'd'),
+    ("echo", 'e'),
+    ("foxtrot", 'f'),
+    ("golf", 'g'),
+    ("hotel", 'h'),
+    ("india", 'i'),
+    ("juliett", 'j'),
+    ("juliet", 'j'),
+    ("kilo", 'k'),
+    ("lima", 'l'),
+    ("mike", 'm'),
+    ("november", 'n'),
+    ("oscar", 'o'),
+    ("papa", 'p'),
+    ("quebec", 'q'),
+    ("romeo", 'r'),
+    ("sierra", 's'),
+    ("tango", 't'),
+    ("uniform", 'u'),
+    ("victor", 'v'),
+    ("whiskey", 'w'),
+    ("whisky", 'w'),
+    ("x-ray", 'x'),
+    ("xray", 'x'),
+    ("yankee", 'y'),
+    ("zulu", 'z'),
+];
+
+/// Phonetic spelling of [`CarettaId`] for spelling ids character by character, e.g. over a phone call.
+///
+/// Letters are spelled with the NATO phonetic alphabet and digits with English words,
+/// e.g. `one two three alfa bravo charlie delta` for `123abcd`.
+///
+/// Parsing is case-insensitive and accepts whitespace, `,` and `.` as separators.
+/// Common variants like `alpha`, `juliet`, `xray`, `niner` and single characters are also accepted,
+/// and letters follow the alias rules of [`DECODE_TABLE`](crate::alphabet::DECODE_TABLE),
+/// so `oscar` is `0` and `lima` and `india` are `1`.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let id: CarettaId = "123abcd".parse()?;
+/// assert_eq!(
+///     id.to_phonetic().to_string(),
+///     "one two three alfa bravo charlie delta"
+/// );
+///
+/// assert_eq!(
+///     CarettaId::from_phonetic("Lima, Two, Three, Alpha, Bravo, Charlie, Delta.")?,
+///     id
+/// );
+/// assert_eq!(CarettaId::from_phonetic("1 2 3 alpha bravo c d")?, id);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CarettaIdPhonetic(CarettaId);
+
+impl CarettaIdPhonetic {
+    /// Creates a new phonetic spelling of the id.
+    pub const fn new(id: CarettaId) -> Self {
+        Self(id)
+    }
+
+    /// Returns the inner [`CarettaId`].
+    pub const fn id(self) -> CarettaId {
+        self.0
+    }
+
+    /// Returns the spoken words of the 7 characters.
+    pub const fn words(self) -> [&'static str; 7] {
+        let value = self.0.to_u64();
+        let mut words = [""; 7];
+        let mut i = 0;
+        while i < 7 {
+            words[i] = SPOKEN[(value >> (30 - 5 * i)) as usize & 0b11111];
+            i += 1;
+        }
+        words
+    }
+}
+
+impl CarettaId {
+    /// Converts to a [`CarettaIdPhonetic`].
+    pub const fn to_phonetic(self) -> CarettaIdPhonetic {
+        CarettaIdPhonetic::new(self)
+    }
+
+    /// Parses the phonetic spelling to [`CarettaId`]. See [`CarettaIdPhonetic`].
+    ///
+    /// # Error
+    ///
+    /// - [`Error::UnknownWord`] with the index of the word if it is neither a known spoken word nor a valid character.
+    /// - [`Error::InvalidLength`] if the number of words is not 7.
+    pub fn from_phonetic(s: &str) -> Result<Self, Error> {
+        s.parse::<CarettaIdPhonetic>().map(CarettaIdPhonetic::id)
+    }
+}
+
+/// Decode the spoken word or single character to 5-bit value.
+fn word_to_u5(word: &str) -> Option<u8> {
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => char_to_u5(c),
+        _ => WORDS
+            .iter()
+            .find(|(x, _)| x.eq_ignore_ascii_case(word))
+            .and_then(|(_, c)| char_to_u5(*c)),
+    }
+}
+
+impl FromStr for CarettaIdPhonetic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value = 0;
+        let mut len = 0;
+        for (index, word) in s
+            .split(|c: char| c.is_whitespace() || matches!(c, ',' | '.'))
+            .filter(|x| !x.is_empty())
+            .enumerate()
+        {
+            let u5 = word_to_u5(word).ok_or(Error::UnknownWord { index })?;
+            value = (value << 5) | u5 as u64;
+            len += 1;
+        }
+        if len == 7 {
+            Ok(Self(CarettaId::from_u64_lossy(value)))
+        } else {
+            Err(Error::InvalidLength(len))
+        }
+    }
+}
+
+impl Display for CarettaIdPhonetic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, word) in self.words().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(word)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<CarettaId> for CarettaIdPhonetic {
+    fn from(value: CarettaId) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<CarettaIdPhonetic> for CarettaId {
+    fn from(value: CarettaIdPhonetic) -> Self {
+        value.id()
+    }
+}
@@ -3,10 +3,20 @@ use ::redb::*;
 
 impl Key for CarettaId {
     fn compare(data1: &[u8], data2: &[u8]) -> std::cmp::Ordering {
-        <Self as Value>::from_bytes(data1).cmp(&<Self as Value>::from_bytes(data2))
+        <u64 as Key>::compare(data1, data2)
     }
 }
 
+/// Stores [`CarettaId`] as [`u64`].
+///
+/// # Panics
+///
+/// Decoding a stored value larger than [`CarettaId::MAX`] panics, because [`Value::from_bytes`] cannot return an error.
+/// This happens on [`AccessGuard::value`] of a key or value read from a corrupted or foreign table, e.g. `table.get(key)?.unwrap().value()`.
+/// Comparing keys does not decode them, so lookups and inserts by key never panic.
+///
+/// To detect such values without panicking, open the same table with [`UncheckedCarettaId`] and check each value,
+/// or use [`LossyCarettaId`](crate::LossyCarettaId) to truncate them.
 impl Value for CarettaId {
     type SelfType<'a> = Self;
     type AsBytes<'a> = <u64 as Value>::AsBytes<'a>;
@@ -17,7 +27,8 @@ impl Value for CarettaId {
     where
         Self: 'a,
     {
-        Self::from_u64_lossy(<u64 as Value>::from_bytes(data))
+        let int = <u64 as Value>::from_bytes(data);
+        Self::from_u64(int).unwrap_or_else(|e| panic!("Failed to decode CarettaId from redb: {e}"))
     }
     fn as_bytes<'a, 'b: 'a>(value: &'a Self::SelfType<'b>) -> Self::AsBytes<'a>
     where
@@ -29,3 +40,92 @@ impl Value for CarettaId {
         TypeName::new(stringify!(CarettaId))
     }
 }
+
+/// Integer value of [`CarettaId`] read from `redb` without validation.
+///
+/// This has the same stored representation and type name as [`CarettaId`],
+/// so a table of [`CarettaId`] can be opened with this type to detect out-of-range values without panicking.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # use redb::{Database, ReadableDatabase, ReadableTable, TableDefinition, backends::InMemoryBackend};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// const TABLE: TableDefinition<UncheckedCarettaId, &str> = TableDefinition::new("note");
+///
+/// let database = Database::builder().create_with_backend(InMemoryBackend::new())?;
+/// let id: CarettaId = "123abcd".parse()?;
+/// let write_txn = database.begin_write()?;
+/// write_txn.open_table(TABLE)?.insert(UncheckedCarettaId::from(id), "hello")?;
+/// write_txn.commit()?;
+///
+/// let read_txn = database.begin_read()?;
+/// for entry in read_txn.open_table(TABLE)?.iter()? {
+///     let (key, _) = entry?;
+///     assert_eq!(key.value().check(), Ok(id));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UncheckedCarettaId(u64);
+
+impl UncheckedCarettaId {
+    /// Returns the stored integer value.
+    pub const fn to_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Converts to [`CarettaId`].
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::ValueOutOfRange`](crate::Error::ValueOutOfRange) if the stored value is larger than [`CarettaId::MAX`].
+    pub const fn check(self) -> Result<CarettaId, crate::Error> {
+        CarettaId::from_u64(self.0)
+    }
+}
+
+impl From<CarettaId> for UncheckedCarettaId {
+    fn from(value: CarettaId) -> Self {
+        Self(value.to_u64())
+    }
+}
+
+impl TryFrom<UncheckedCarettaId> for CarettaId {
+    type Error = crate::Error;
+
+    fn try_from(value: UncheckedCarettaId) -> Result<Self, Self::Error> {
+        value.check()
+    }
+}
+
+impl Key for UncheckedCarettaId {
+    fn compare(data1: &[u8], data2: &[u8]) -> std::cmp::Ordering {
+        <CarettaId as Key>::compare(data1, data2)
+    }
+}
+
+impl Value for UncheckedCarettaId {
+    type SelfType<'a> = Self;
+    type AsBytes<'a> = <u64 as Value>::AsBytes<'a>;
+    fn fixed_width() -> Option<usize> {
+        <u64 as Value>::fixed_width()
+    }
+    fn from_bytes<'a>(data: &'a [u8]) -> Self::SelfType<'a>
+    where
+        Self: 'a,
+    {
+        Self(<u64 as Value>::from_bytes(data))
+    }
+    fn as_bytes<'a, 'b: 'a>(value: &'a Self::SelfType<'b>) -> Self::AsBytes<'a>
+    where
+        Self: 'b,
+    {
+        <u64 as Value>::as_bytes(&value.0)
+    }
+    fn type_name() -> TypeName {
+        <CarettaId as Value>::type_name()
+    }
+}
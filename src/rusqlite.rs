@@ -1,10 +1,13 @@
 use super::CarettaId;
-use rusqlite::{ToSql, types::FromSql};
+use rusqlite::{
+    ToSql,
+    types::{FromSql, FromSqlError},
+};
 
 impl FromSql for CarettaId {
     fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
         let int = u64::column_result(value)?;
-        Ok(Self::from_u64_lossy(int))
+        Self::from_u64(int).map_err(|_| FromSqlError::OutOfRange(int as i64))
     }
 }
 
@@ -0,0 +1,202 @@
+use core::ops::Range;
+
+use crate::{CarettaId, alphabet::DECODE_TABLE};
+
+/// Scanner finding [`CarettaId`] occurrences in free text, such as commit messages, notes and chat.
+///
+/// A candidate is a run of exactly 7 characters decodable with [`DECODE_TABLE`],
+/// so alias characters like `o`, `l` and uppercase letters are also detected.
+/// The candidate must be surrounded by word boundaries,
+/// that is, neither preceded nor followed by alphanumeric characters or `_`.
+///
+/// Without sigil, any 7-letter word consisting of decodable characters (e.g. `example`) is detected.
+/// Set a sigil with [`sigil`](Self::sigil) to detect only the explicitly marked ids like `#123abcd`.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let text = "see #123abcd and 456wxyz, not x789abcd";
+///
+/// let ids: Vec<CarettaId> = CarettaIdScanner::new().find_iter(text).map(|m| m.id()).collect();
+/// assert_eq!(ids, vec!["123abcd".parse()?, "456wxyz".parse()?]);
+///
+/// let matches: Vec<_> = CarettaIdScanner::new().sigil('#').find_iter(text).collect();
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(matches[0].as_str(), "#123abcd");
+/// assert_eq!(matches[0].range(), 4..12);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct CarettaIdScanner {
+    sigil: Option<char>,
+}
+
+impl CarettaIdScanner {
+    /// Creates a new scanner without sigil.
+    pub const fn new() -> Self {
+        Self { sigil: None }
+    }
+
+    /// Requires the sigil character (e.g. `#`) immediately before ids.
+    ///
+    /// The sigil is included in the span of the match.
+    pub const fn sigil(mut self, sigil: char) -> Self {
+        self.sigil = Some(sigil);
+        self
+    }
+
+    /// Returns an iterator over all occurrences in the text.
+    pub fn find_iter<'t>(&self, text: &'t str) -> CarettaIdMatches<'t> {
+        CarettaIdMatches {
+            sigil: self.sigil,
+            text,
+            position: 0,
+        }
+    }
+
+    /// Returns the first occurrence in the text.
+    pub fn find<'t>(&self, text: &'t str) -> Option<CarettaIdMatch<'t>> {
+        self.find_iter(text).next()
+    }
+
+    /// Replaces all occurrences in the text with the result of the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// let scanner = CarettaIdScanner::new().sigil('#');
+    ///
+    /// // Turn ids into links.
+    /// assert_eq!(
+    ///     scanner.replace("fixed in #123abcd.", |m| format!("[{}](/notes/{})", m.as_str(), m.id())),
+    ///     "fixed in [#123abcd](/notes/123abcd)."
+    /// );
+    ///
+    /// // Normalize aliases to canonical form.
+    /// assert_eq!(
+    ///     scanner.replace("see #OL2ABCD", |m| format!("#{}", m.id())),
+    ///     "see #012abcd"
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn replace<F, T>(&self, text: &str, mut replacer: F) -> String
+    where
+        F: FnMut(&CarettaIdMatch<'_>) -> T,
+        T: core::fmt::Display,
+    {
+        use core::fmt::Write;
+
+        let mut result = String::with_capacity(text.len());
+        let mut last = 0;
+        for m in self.find_iter(text) {
+            result.push_str(&text[last..m.start]);
+            write!(result, "{}", replacer(&m)).unwrap();
+            last = m.end;
+        }
+        result.push_str(&text[last..]);
+        result
+    }
+}
+
+/// An occurrence of [`CarettaId`] found by [`CarettaIdScanner`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CarettaIdMatch<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+    id: CarettaId,
+}
+
+impl<'t> CarettaIdMatch<'t> {
+    /// Returns the decoded id.
+    pub const fn id(&self) -> CarettaId {
+        self.id
+    }
+
+    /// Returns the byte offset of the start of the match, including the sigil.
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset of the end of the match.
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the byte range of the match, including the sigil.
+    pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the matched text, including the sigil.
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.range()]
+    }
+}
+
+/// Iterator over [`CarettaIdMatch`], created by [`CarettaIdScanner::find_iter`].
+#[derive(Clone, Debug)]
+pub struct CarettaIdMatches<'t> {
+    sigil: Option<char>,
+    text: &'t str,
+    position: usize,
+}
+
+impl<'t> Iterator for CarettaIdMatches<'t> {
+    type Item = CarettaIdMatch<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.text[self.position..].chars().next() {
+            let start = self.position;
+            if let Some(m) = self.match_at(start) {
+                self.position = m.end;
+                return Some(m);
+            }
+            self.position += c.len_utf8();
+        }
+        None
+    }
+}
+
+impl<'t> CarettaIdMatches<'t> {
+    fn match_at(&self, start: usize) -> Option<CarettaIdMatch<'t>> {
+        if self.text[..start].chars().next_back().is_some_and(is_word) {
+            return None;
+        }
+        let body = match self.sigil {
+            Some(sigil) => {
+                start
+                    + self.text[start..]
+                        .strip_prefix(sigil)
+                        .map(|_| sigil.len_utf8())?
+            }
+            None => start,
+        };
+        let end = body + 7;
+        let bytes = self.text.as_bytes().get(body..end)?;
+        let mut value = 0;
+        for byte in bytes {
+            match DECODE_TABLE[*byte as usize] {
+                u8::MAX => return None,
+                x => value = (value << 5) | x as u64,
+            }
+        }
+        if self.text[end..].chars().next().is_some_and(is_word) {
+            return None;
+        }
+        Some(CarettaIdMatch {
+            text: self.text,
+            start,
+            end,
+            id: CarettaId::from_u64_lossy(value),
+        })
+    }
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
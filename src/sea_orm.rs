@@ -13,13 +13,15 @@ impl sea_orm::TryGetable for CarettaId {
         res: &sea_orm::QueryResult,
         index: I,
     ) -> Result<Self, sea_orm::TryGetError> {
-        <u64 as sea_orm::TryGetable>::try_get_by(res, index).map(|x| CarettaId::from_u64_lossy(x))
+        <u64 as sea_orm::TryGetable>::try_get_by(res, index)
+            .and_then(|x| CarettaId::try_from_u64(x).map_err(sea_orm::TryGetError::DbErr))
     }
 }
 
 impl sea_orm::sea_query::ValueType for CarettaId {
     fn try_from(v: sea_orm::Value) -> Result<Self, sea_orm::sea_query::ValueTypeErr> {
-        <u64 as sea_orm::sea_query::ValueType>::try_from(v).map(|x| CarettaId::from_u64_lossy(x))
+        <u64 as sea_orm::sea_query::ValueType>::try_from(v)
+            .and_then(|x| CarettaId::from_u64(x).map_err(|_| sea_orm::sea_query::ValueTypeErr))
     }
     fn type_name() -> String {
         stringify!(CarettaId).to_owned()
@@ -7,13 +7,7 @@ impl Serialize for CarettaId {
         S: ::serde::Serializer,
     {
         if serializer.is_human_readable() {
-            let chars = self.to_chars();
-            let mut buf = [0; 7];
-            for i in 0..7 {
-                buf[i] = u8::try_from(chars[i]).unwrap();
-            }
-            let s = unsafe { str::from_utf8_unchecked(&buf) };
-            serializer.serialize_str(s)
+            serializer.serialize_str(&self.encode())
         } else {
             serializer.serialize_u64(self.to_u64())
         }
@@ -44,3 +38,32 @@ impl<'de> Deserialize<'de> for CarettaId {
         }
     }
 }
+
+impl Serialize for AnyCarettaId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AnyCarettaId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        #[cfg(feature = "std")]
+        {
+            <String as Deserialize>::deserialize(deserializer)?
+                .parse::<AnyCarettaId>()
+                .map_err(D::Error::custom)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            (<&str as Deserialize>::deserialize(deserializer)?)
+                .parse::<AnyCarettaId>()
+                .map_err(D::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use rusqlite::{Connection, Error, functions::FunctionFlags, types::ValueRef};
+
+use crate::CarettaId;
+
+/// Registers SQL functions to work with [`CarettaId`]s stored as integers on the SQLite connection.
+///
+/// - `caretta_id_encode(int)` returns the textual representation of the integer. Fails if the integer is out of range.
+/// - `caretta_id_decode(text)` returns the integer of the textual representation. Fails if the text is invalid.
+/// - `caretta_id_is_valid(value)` returns `1` if the text is accepted by `caretta_id_decode` or the integer is in range, otherwise `0`.
+/// - `caretta_id_random()` returns a random integer. Registered only with `rand` feature.
+///
+/// Every function except `caretta_id_random` returns `NULL` for `NULL`.
+///
+/// The functions exist only on connections that called this function, not in the database file.
+/// Views, defaults and triggers may still use them, e.g. a view showing ids in the human-readable form
+/// or `DEFAULT (caretta_id_random())`, but any other connection, including tools like the `sqlite3` shell,
+/// gets `no such function` error when it queries such a view or inserts into such a table relying on the default.
+/// To use them safely, call this function right after opening every connection to the database,
+/// e.g. in the initialization hook of the connection pool,
+/// and let other tools read the underlying tables instead of the views.
+///
+/// Note that `caretta_id_random` does not avoid collisions,
+/// so prefer [`CarettaIdStore::generate_unique`](crate::CarettaIdStore::generate_unique) to generate primary keys.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "default", doc = "```rust")]
+#[cfg_attr(not(feature = "default"), doc = "```ignore")]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn = rusqlite::Connection::open_in_memory()?;
+/// register_sqlite_functions(&conn)?;
+///
+/// let id: CarettaId = "123abcd".parse()?;
+/// let text: String = conn.query_row("SELECT caretta_id_encode(?1)", [id], |row| row.get(0))?;
+/// assert_eq!(text, "123abcd");
+///
+/// let decoded: CarettaId =
+///     conn.query_row("SELECT caretta_id_decode('123-ABCD')", (), |row| row.get(0))?;
+/// assert_eq!(decoded, id);
+///
+/// let valid: bool = conn.query_row("SELECT caretta_id_is_valid('123abc!')", (), |row| row.get(0))?;
+/// assert!(!valid);
+///
+/// conn.execute_batch(
+///     "CREATE TABLE note (id INTEGER PRIMARY KEY DEFAULT (caretta_id_random()), body TEXT);
+///      CREATE VIEW note_view AS SELECT caretta_id_encode(id) AS id, body FROM note;",
+/// )?;
+/// conn.execute("INSERT INTO note (body) VALUES ('hello')", ())?;
+/// let text: String = conn.query_row("SELECT id FROM note_view", (), |row| row.get(0))?;
+/// assert!(text.parse::<CarettaId>().is_ok());
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_sqlite_functions(conn: &Connection) -> rusqlite::Result<()> {
+    let deterministic = FunctionFlags::SQLITE_UTF8
+        | FunctionFlags::SQLITE_DETERMINISTIC
+        | FunctionFlags::SQLITE_INNOCUOUS;
+    conn.create_scalar_function("caretta_id_encode", 1, deterministic, |ctx| {
+        Ok(ctx.get::<Option<CarettaId>>(0)?.map(|id| id.to_string()))
+    })?;
+    conn.create_scalar_function("caretta_id_decode", 1, deterministic, |ctx| {
+        ctx.get::<Option<String>>(0)?
+            .map(|s| s.parse::<CarettaId>())
+            .transpose()
+            .map_err(|e| Error::UserFunctionError(Box::new(e)))
+    })?;
+    conn.create_scalar_function("caretta_id_is_valid", 1, deterministic, |ctx| {
+        Ok(match ctx.get_raw(0) {
+            ValueRef::Null => None,
+            ValueRef::Integer(int) => {
+                Some(u64::try_from(int).is_ok_and(|x| CarettaId::from_u64(x).is_ok()))
+            }
+            ValueRef::Text(text) => {
+                Some(core::str::from_utf8(text).is_ok_and(|s| s.parse::<CarettaId>().is_ok()))
+            }
+            _ => Some(false),
+        })
+    })?;
+    #[cfg(feature = "rand")]
+    conn.create_scalar_function(
+        "caretta_id_random",
+        0,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_INNOCUOUS,
+        |_| Ok(CarettaId::random()),
+    )?;
+    Ok(())
+}
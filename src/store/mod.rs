@@ -0,0 +1,120 @@
+#[cfg(feature = "redb")]
+mod redb;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
+#[cfg(feature = "rusqlite")]
+pub use rusqlite::CarettaIdColumn;
+#[cfg(feature = "rusqlite")]
+pub(crate) use rusqlite::quote;
+
+use crate::CarettaId;
+
+/// Storage of existing [`CarettaId`]s used to avoid collisions on generation.
+///
+/// [`CarettaId::random`] has only 35 bits, so collisions are realistic once a table holds a few hundred thousand rows.
+/// [`generate_unique`](Self::generate_unique) retries until it finds an id which is not in the store.
+///
+/// This trait is implemented for `HashSet<CarettaId>` and `BTreeSet<CarettaId>`,
+/// [`CarettaIdColumn`] with `rusqlite` feature, and `redb::Table<CarettaId, ()>` with `redb` feature.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "default", doc = "```rust")]
+#[cfg_attr(not(feature = "default"), doc = "```ignore")]
+/// # use caretta_id::*;
+/// # use std::collections::HashSet;
+/// # fn main() -> Result<(), GenerateUniqueError<core::convert::Infallible>> {
+/// let mut ids = HashSet::new();
+/// let id = ids.generate_unique(10)?;
+/// assert!(ids.contains(&id));
+///
+/// // Nothing left to generate.
+/// let mut ids = HashSet::from([CarettaId::NIL]);
+/// assert_eq!(
+///     ids.generate_unique_with(3, || CarettaId::NIL),
+///     Err(GenerateUniqueError::AttemptsExhausted(3))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub trait CarettaIdStore {
+    /// Error returned from the underlying storage.
+    type Error;
+
+    /// Returns `true` if the id exists in the store.
+    fn contains_id(&self, id: CarettaId) -> Result<bool, Self::Error>;
+
+    /// Reserves the id in the store.
+    ///
+    /// Returns `false` if the id already exists.
+    fn reserve_id(&mut self, id: CarettaId) -> Result<bool, Self::Error>;
+
+    /// Reserves and returns the first id returned from the generator which does not exist in the store.
+    ///
+    /// # Error
+    ///
+    /// Returns [`GenerateUniqueError::AttemptsExhausted`] if all of `max_attempts` ids already exist.
+    fn generate_unique_with<F>(
+        &mut self,
+        max_attempts: usize,
+        mut generator: F,
+    ) -> Result<CarettaId, GenerateUniqueError<Self::Error>>
+    where
+        F: FnMut() -> CarettaId,
+    {
+        for _ in 0..max_attempts {
+            let id = generator();
+            if self.reserve_id(id).map_err(GenerateUniqueError::Store)? {
+                return Ok(id);
+            }
+        }
+        Err(GenerateUniqueError::AttemptsExhausted(max_attempts))
+    }
+
+    /// Reserves and returns a random id which does not exist in the store.
+    ///
+    /// See [`generate_unique_with`](Self::generate_unique_with) for more details.
+    #[cfg(feature = "rand")]
+    fn generate_unique(
+        &mut self,
+        max_attempts: usize,
+    ) -> Result<CarettaId, GenerateUniqueError<Self::Error>> {
+        self.generate_unique_with(max_attempts, CarettaId::random)
+    }
+}
+
+/// An error that can occur when generating unique id with [`CarettaIdStore`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+pub enum GenerateUniqueError<E> {
+    #[error("Failed to generate unique caretta-id within {0} attempts")]
+    AttemptsExhausted(usize),
+    #[error("Store error: {0}")]
+    Store(E),
+}
+
+#[cfg(feature = "std")]
+impl<S: std::hash::BuildHasher> CarettaIdStore for std::collections::HashSet<CarettaId, S> {
+    type Error = core::convert::Infallible;
+
+    fn contains_id(&self, id: CarettaId) -> Result<bool, Self::Error> {
+        Ok(self.contains(&id))
+    }
+
+    fn reserve_id(&mut self, id: CarettaId) -> Result<bool, Self::Error> {
+        Ok(self.insert(id))
+    }
+}
+
+#[cfg(feature = "std")]
+impl CarettaIdStore for std::collections::BTreeSet<CarettaId> {
+    type Error = core::convert::Infallible;
+
+    fn contains_id(&self, id: CarettaId) -> Result<bool, Self::Error> {
+        Ok(self.contains(&id))
+    }
+
+    fn reserve_id(&mut self, id: CarettaId) -> Result<bool, Self::Error> {
+        Ok(self.insert(id))
+    }
+}
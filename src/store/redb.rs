@@ -0,0 +1,17 @@
+use redb::{ReadableTable, StorageError, Table};
+
+use super::CarettaIdStore;
+use crate::CarettaId;
+
+/// Keys are only compared as integers and never decoded, so out-of-range keys stored in the table do not panic.
+impl CarettaIdStore for Table<'_, CarettaId, ()> {
+    type Error = StorageError;
+
+    fn contains_id(&self, id: CarettaId) -> Result<bool, Self::Error> {
+        self.get(id).map(|x| x.is_some())
+    }
+
+    fn reserve_id(&mut self, id: CarettaId) -> Result<bool, Self::Error> {
+        self.insert(id, ()).map(|x| x.is_none())
+    }
+}
@@ -0,0 +1,82 @@
+use rusqlite::{Connection, OptionalExtension};
+
+use super::CarettaIdStore;
+use crate::CarettaId;
+
+/// Column of SQLite table storing [`CarettaId`]s, used as [`CarettaIdStore`].
+///
+/// Reserving an id inserts a new row with only the column specified,
+/// so the other columns of the table must be nullable or have default values.
+/// The column must have `UNIQUE` or `PRIMARY KEY` constraint.
+/// Only a conflict on the column is reported as an existing id, and any other error, e.g. a `NOT NULL` violation, is returned as is.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "default", doc = "```rust")]
+#[cfg_attr(not(feature = "default"), doc = "```ignore")]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn = rusqlite::Connection::open_in_memory()?;
+/// conn.execute("CREATE TABLE note (id INTEGER PRIMARY KEY, body TEXT)", ())?;
+///
+/// let mut column = CarettaIdColumn::new(&conn, "note", "id");
+/// let id = column.generate_unique(10)?;
+/// assert!(column.contains_id(id)?);
+/// assert!(!column.reserve_id(id)?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CarettaIdColumn<'a> {
+    pub(crate) conn: &'a Connection,
+    pub(crate) table: &'a str,
+    pub(crate) column: &'a str,
+}
+
+impl<'a> CarettaIdColumn<'a> {
+    /// Creates a new store from the table and column names.
+    pub const fn new(conn: &'a Connection, table: &'a str, column: &'a str) -> Self {
+        Self {
+            conn,
+            table,
+            column,
+        }
+    }
+}
+
+/// Quote the identifier of SQLite.
+pub(crate) fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+impl CarettaIdStore for CarettaIdColumn<'_> {
+    type Error = rusqlite::Error;
+
+    fn contains_id(&self, id: CarettaId) -> Result<bool, Self::Error> {
+        self.conn
+            .query_row(
+                &format!(
+                    "SELECT 1 FROM {} WHERE {} = ?1 LIMIT 1",
+                    quote(self.table),
+                    quote(self.column)
+                ),
+                [id],
+                |_| Ok(()),
+            )
+            .optional()
+            .map(|x| x.is_some())
+    }
+
+    fn reserve_id(&mut self, id: CarettaId) -> Result<bool, Self::Error> {
+        self.conn
+            .execute(
+                &format!(
+                    "INSERT INTO {table} ({column}) VALUES (?1) ON CONFLICT ({column}) DO NOTHING",
+                    table = quote(self.table),
+                    column = quote(self.column)
+                ),
+                [id],
+            )
+            .map(|x| x == 1)
+    }
+}
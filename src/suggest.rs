@@ -0,0 +1,171 @@
+use crate::{
+    CarettaId,
+    alphabet::{char_is_delimiter, char_to_u5},
+};
+
+/// Cost of an ordinary edit, such as substitution, insertion, deletion and adjacent transposition.
+const EDIT_COST: u32 = 2;
+
+/// Cost of a substitution between confusable characters, or an insertion of a doubled character.
+const CONFUSABLE_COST: u32 = 1;
+
+/// Pairs of 5-bit values whose characters are visually or phonetically confusable.
+///
+/// Aliases already handled by [`DECODE_TABLE`](crate::alphabet::DECODE_TABLE) (e.g. `o`/`0`, `l`/`1`, `u`/`v`) cost nothing
+/// and are not listed here.
+const CONFUSABLE_PAIRS: &[(u8, u8)] = &[
+    (5, 25),  // 5 / s
+    (2, 31),  // 2 / z
+    (8, 11),  // 8 / b
+    (6, 11),  // 6 / b
+    (6, 16),  // 6 / g
+    (9, 16),  // 9 / g
+    (9, 23),  // 9 / q
+    (0, 13),  // 0 / d
+    (1, 7),   // 1 / 7
+    (1, 18),  // 1 / j
+    (27, 30), // v / y
+    (20, 21), // m / n
+    (11, 22), // b / p
+    (13, 26), // d / t
+    (16, 18), // g / j
+];
+
+fn substitution_cost(input: Option<u8>, expected: u8) -> u32 {
+    match input {
+        Some(x) if x == expected => 0,
+        Some(x)
+            if CONFUSABLE_PAIRS
+                .iter()
+                .any(|&(a, b)| (a, b) == (x, expected) || (b, a) == (x, expected)) =>
+        {
+            CONFUSABLE_COST
+        }
+        _ => EDIT_COST,
+    }
+}
+
+impl CarettaId {
+    /// Returns the typo-tolerant edit distance from the input string to this id.
+    ///
+    /// The input is decoded with the same rules as parsing, so aliases like `o`/`0` and uppercase letters cost nothing
+    /// and delimiters (`-` and `_`) are ignored.
+    /// Ordinary substitutions, insertions, deletions and transpositions of adjacent characters cost 2,
+    /// while substitutions between confusable characters (e.g. `5`/`s`, `2`/`z`, `8`/`b`, `m`/`n`) and doubled characters cost 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let id: CarettaId = "123abcd".parse()?;
+    /// assert_eq!(id.typo_distance("123abcd"), 0);
+    /// assert_eq!(id.typo_distance("l23-ABCD"), 0);
+    /// assert_eq!(id.typo_distance("123a8cd"), 1);
+    /// assert_eq!(id.typo_distance("1233abcd"), 1);
+    /// assert_eq!(id.typo_distance("123acd"), 2);
+    /// assert_eq!(id.typo_distance("132abcd"), 2);
+    /// assert_eq!(id.typo_distance("123xbcd"), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn typo_distance(&self, input: &str) -> u32 {
+        let expected = self.to_u5s();
+        // Optimal string alignment distance, keeping only the last two rows over the input characters.
+        let mut before_previous = [0; 8];
+        let mut previous: [u32; 8] = core::array::from_fn(|j| j as u32 * EDIT_COST);
+        let mut previous_inputs: [Option<Option<u8>>; 2] = [None, None];
+        for c in input.chars().filter(|c| !char_is_delimiter(*c)) {
+            let current_input = char_to_u5(c);
+            let insertion_cost = if previous_inputs[1] == Some(current_input) {
+                CONFUSABLE_COST
+            } else {
+                EDIT_COST
+            };
+            let mut current = [previous[0] + insertion_cost; 8];
+            for j in 1..8 {
+                current[j] = u32::min(
+                    u32::min(previous[j] + insertion_cost, current[j - 1] + EDIT_COST),
+                    previous[j - 1] + substitution_cost(current_input, expected[j - 1]),
+                );
+                if j > 1
+                    && let Some(Some(previous_input)) = previous_inputs[1]
+                    && current_input == Some(expected[j - 2])
+                    && previous_input == expected[j - 1]
+                    && previous_input != expected[j - 2]
+                {
+                    current[j] = u32::min(current[j], before_previous[j - 2] + EDIT_COST);
+                }
+            }
+            before_previous = previous;
+            previous = current;
+            previous_inputs = [previous_inputs[1], Some(current_input)];
+        }
+        previous[7]
+    }
+
+    fn to_u5s(self) -> [u8; 7] {
+        core::array::from_fn(|i| (self.to_u64() >> (30 - 5 * i)) as u8 & 0b11111)
+    }
+}
+
+/// A candidate of "did you mean" suggestions, returned by [`CarettaIdIndex::suggest`](crate::CarettaIdIndex::suggest).
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CarettaIdSuggestion {
+    id: CarettaId,
+    distance: u32,
+}
+
+#[cfg(feature = "std")]
+impl CarettaIdSuggestion {
+    /// Returns the suggested id.
+    pub const fn id(&self) -> CarettaId {
+        self.id
+    }
+
+    /// Returns the typo-tolerant edit distance. See [`CarettaId::typo_distance`].
+    pub const fn distance(&self) -> u32 {
+        self.distance
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::CarettaIdIndex {
+    /// Returns the stored ids within `max_distance` from the mistyped input, nearest first.
+    ///
+    /// Ids of the same distance are ordered ascending.
+    /// See [`CarettaId::typo_distance`] for the definition of the distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use caretta_id::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let index: CarettaIdIndex = ["123abcd", "123abce", "456wxyz"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<CarettaId>())
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// let suggestions = index.suggest("l23a8cd", 3);
+    /// assert_eq!(suggestions.len(), 2);
+    /// assert_eq!(suggestions[0].id(), "123abcd".parse()?);
+    /// assert_eq!(suggestions[0].distance(), 1);
+    /// assert_eq!(suggestions[1].id(), "123abce".parse()?);
+    /// assert_eq!(suggestions[1].distance(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn suggest(&self, input: &str, max_distance: u32) -> Vec<CarettaIdSuggestion> {
+        let mut suggestions: Vec<CarettaIdSuggestion> = self
+            .iter()
+            .map(|id| CarettaIdSuggestion {
+                id: *id,
+                distance: id.typo_distance(input),
+            })
+            .filter(|x| x.distance <= max_distance)
+            .collect();
+        suggestions.sort_by_key(|x| x.distance);
+        suggestions
+    }
+}
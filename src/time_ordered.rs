@@ -0,0 +1,243 @@
+use crate::{CarettaId, Error};
+
+/// Generator of [`CarettaId`] sorted roughly by creation time, like ULID.
+///
+/// The upper `timestamp_bits` bits of the 35 bits hold the number of ticks elapsed since the custom epoch,
+/// and the remaining lower bits hold random value.
+/// Because of the limited bit width, the timestamp is coarse and covers limited period.
+/// For example, 20 bits of hours cover about 119 years from the epoch and leave 15 bits for randomness.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "default", doc = "```rust")]
+#[cfg_attr(not(feature = "default"), doc = "```ignore")]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// // Hourly ticks since 2025-01-01T00:00:00Z.
+/// let generator = TimeOrderedGenerator::new(1735689600, 3600, 20)?;
+///
+/// let earlier = generator.from_unix_time(1735689600 + 3600, 12345)?;
+/// let later = generator.from_unix_time(1735689600 + 7200, 0)?;
+/// assert!(earlier < later);
+///
+/// // Creation time is rounded down to the tick.
+/// assert_eq!(generator.unix_time(earlier), 1735689600 + 3600);
+///
+/// // Generate from current time.
+/// let id = generator.random()?;
+/// assert!(generator.unix_time(id) <= generator.unix_time(generator.random()?));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TimeOrderedGenerator {
+    epoch: u64,
+    resolution: u64,
+    timestamp_bits: u32,
+}
+
+impl TimeOrderedGenerator {
+    /// Creates a new generator.
+    ///
+    /// - `epoch`: Start of the timestamp in seconds since the Unix epoch.
+    /// - `resolution`: Length of a tick in seconds, e.g. `60` for minutes or `3600` for hours.
+    /// - `timestamp_bits`: Number of upper bits used for the timestamp.
+    ///
+    /// # Error
+    ///
+    /// - [`Error::InvalidBitWidth`] unless `timestamp_bits` is between 1 and 34,
+    ///   so that at least 1 bit is left for randomness.
+    /// - [`Error::InvalidResolution`] if `resolution` is zero,
+    ///   or the start of the last tick does not fit in [`u64`] seconds since the Unix epoch.
+    pub const fn new(epoch: u64, resolution: u64, timestamp_bits: u32) -> Result<Self, Error> {
+        if timestamp_bits == 0 || timestamp_bits >= CarettaId::BITS {
+            return Err(Error::InvalidBitWidth(timestamp_bits));
+        }
+        if resolution == 0 {
+            return Err(Error::InvalidResolution(resolution));
+        }
+        let last_tick: u64 = (1 << timestamp_bits) - 1;
+        match last_tick.checked_mul(resolution) {
+            Some(x) if epoch.checked_add(x).is_some() => {}
+            _ => return Err(Error::InvalidResolution(resolution)),
+        }
+        Ok(Self {
+            epoch,
+            resolution,
+            timestamp_bits,
+        })
+    }
+
+    /// Returns the start of the timestamp in seconds since the Unix epoch.
+    pub const fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the length of a tick in seconds.
+    pub const fn resolution(&self) -> u64 {
+        self.resolution
+    }
+
+    /// Returns the number of upper bits used for the timestamp.
+    pub const fn timestamp_bits(&self) -> u32 {
+        self.timestamp_bits
+    }
+
+    /// Returns the number of lower bits used for randomness.
+    pub const fn random_bits(&self) -> u32 {
+        CarettaId::BITS - self.timestamp_bits
+    }
+
+    const fn random_mask(&self) -> u64 {
+        (1 << self.random_bits()) - 1
+    }
+
+    /// Creates a [`CarettaId`] from the time in seconds since the Unix epoch and random value.
+    ///
+    /// Bits of `random` exceeding [`random_bits`](Self::random_bits) are truncated.
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::TimestampOutOfRange`] if the time is before the epoch or too late to be represented.
+    pub const fn from_unix_time(&self, secs: u64, random: u64) -> Result<CarettaId, Error> {
+        if secs < self.epoch {
+            return Err(Error::TimestampOutOfRange(secs));
+        }
+        let tick = (secs - self.epoch) / self.resolution;
+        if tick >> self.timestamp_bits != 0 {
+            return Err(Error::TimestampOutOfRange(secs));
+        }
+        Ok(CarettaId::from_u64_lossy(
+            (tick << self.random_bits()) | (random & self.random_mask()),
+        ))
+    }
+
+    /// Returns the number of ticks elapsed from the epoch when the id was created.
+    pub const fn tick(&self, id: CarettaId) -> u64 {
+        id.to_u64() >> self.random_bits()
+    }
+
+    /// Returns the approximate creation time of the id in seconds since the Unix epoch.
+    ///
+    /// The time is rounded down to the start of the tick.
+    pub const fn unix_time(&self, id: CarettaId) -> u64 {
+        self.epoch + self.tick(id) * self.resolution
+    }
+
+    /// Returns the approximate creation time of the id.
+    ///
+    /// Returns `None` if the time cannot be represented by [`SystemTime`](std::time::SystemTime) on the platform.
+    /// See [`unix_time`](Self::unix_time) for details.
+    #[cfg(feature = "std")]
+    pub fn system_time(&self, id: CarettaId) -> Option<std::time::SystemTime> {
+        std::time::UNIX_EPOCH.checked_add(std::time::Duration::from_secs(self.unix_time(id)))
+    }
+
+    /// Generates a new [`CarettaId`] from the current system time and random value.
+    ///
+    /// # Error
+    ///
+    /// Returns [`Error::TimestampOutOfRange`] if the current time is out of the range of this generator.
+    #[cfg(all(feature = "std", feature = "rand"))]
+    pub fn random(&self) -> Result<CarettaId, Error> {
+        self.from_unix_time(now(), ::rand::random())
+    }
+
+    /// Converts to a [`MonotonicTimeOrderedGenerator`].
+    pub const fn monotonic(self) -> MonotonicTimeOrderedGenerator {
+        MonotonicTimeOrderedGenerator::new(self)
+    }
+}
+
+/// [`TimeOrderedGenerator`] guaranteeing that each generated id is greater than the previous one.
+///
+/// Within the same tick, the previous id is incremented instead of drawing new random bits.
+/// If the clock goes backwards, the previous tick continues to be used.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// let mut generator = TimeOrderedGenerator::new(0, 60, 30)?.monotonic();
+///
+/// let first = generator.from_unix_time(600, 20)?;
+/// // Smaller random value in the same tick.
+/// let second = generator.from_unix_time(630, 10)?;
+/// assert_eq!(second.to_u64(), first.to_u64() + 1);
+///
+/// // Next tick uses random value as is.
+/// let third = generator.from_unix_time(660, 10)?;
+/// assert!(second < third);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MonotonicTimeOrderedGenerator {
+    generator: TimeOrderedGenerator,
+    last: Option<CarettaId>,
+}
+
+impl MonotonicTimeOrderedGenerator {
+    /// Creates a new monotonic generator.
+    pub const fn new(generator: TimeOrderedGenerator) -> Self {
+        Self {
+            generator,
+            last: None,
+        }
+    }
+
+    /// Returns the underlying [`TimeOrderedGenerator`].
+    pub const fn generator(&self) -> &TimeOrderedGenerator {
+        &self.generator
+    }
+
+    /// Returns the last generated id.
+    pub const fn last(&self) -> Option<CarettaId> {
+        self.last
+    }
+
+    /// Creates a [`CarettaId`] greater than the last generated one.
+    ///
+    /// # Error
+    ///
+    /// - [`Error::TimestampOutOfRange`] if the time is out of the range of the generator.
+    /// - [`Error::MonotonicOverflow`] if the random bits of the current tick are exhausted.
+    ///
+    /// See [`TimeOrderedGenerator::from_unix_time`] for details.
+    pub fn from_unix_time(&mut self, secs: u64, random: u64) -> Result<CarettaId, Error> {
+        let mut id = self.generator.from_unix_time(secs, random)?;
+        if let Some(last) = self.last
+            && id <= last
+        {
+            let next = last.to_u64() + 1;
+            if next & self.generator.random_mask() == 0 {
+                return Err(Error::MonotonicOverflow);
+            }
+            id = CarettaId::from_u64_lossy(next);
+        }
+        self.last = Some(id);
+        Ok(id)
+    }
+
+    /// Generates a new [`CarettaId`] greater than the last generated one from the current system time.
+    ///
+    /// See [`from_unix_time`](Self::from_unix_time) for details.
+    #[cfg(all(feature = "std", feature = "rand"))]
+    pub fn random(&mut self) -> Result<CarettaId, Error> {
+        self.from_unix_time(now(), ::rand::random())
+    }
+}
+
+impl From<TimeOrderedGenerator> for MonotonicTimeOrderedGenerator {
+    fn from(value: TimeOrderedGenerator) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "rand"))]
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs())
+}
@@ -0,0 +1,8 @@
+use super::{CarettaIdKind, TypedCarettaId};
+use crate::CarettaId;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+impl<'a, K: CarettaIdKind> Arbitrary<'a> for TypedCarettaId<K> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        CarettaId::arbitrary(u).map(Self::new)
+    }
+}
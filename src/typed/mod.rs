@@ -0,0 +1,244 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "prost")]
+mod prost;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "redb")]
+mod redb;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+#[cfg(feature = "serde")]
+mod serde;
+
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::Hash,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use crate::{CarettaId, Error};
+
+/// Marker trait specifying the kind of [`TypedCarettaId`].
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// pub enum User {}
+///
+/// impl CarettaIdKind for User {
+///     const PREFIX: Option<&'static str> = Some("usr");
+/// }
+///
+/// pub type UserId = TypedCarettaId<User>;
+/// ```
+pub trait CarettaIdKind {
+    /// Textual prefix of the id, e.g. `usr` for `usr_123abcd`.
+    ///
+    /// Defaults to `None`, which means the id is represented same as [`CarettaId`].
+    const PREFIX: Option<&'static str> = None;
+
+    /// Separator between the prefix and the id.
+    const SEPARATOR: char = '_';
+}
+
+/// Type-safe [`CarettaId`] newtype distinguished by its [`CarettaIdKind`].
+///
+/// Every integration implemented for [`CarettaId`] (`serde`, `rusqlite`, `sea-orm`, `redb`, `prost`, `rand` and `arbitrary`)
+/// is also implemented for this type.
+/// The textual representation has the prefix of the kind, while the integer representation is same as [`CarettaId`].
+///
+/// # Panics
+///
+/// With `redb` feature, decoding a stored value larger than [`CarettaId::MAX`] panics, same as [`CarettaId`].
+/// See the `redb` [`Value`](::redb::Value) implementation of [`CarettaId`] for details.
+///
+/// # Examples
+///
+/// ```
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// pub enum User {}
+/// impl CarettaIdKind for User {
+///     const PREFIX: Option<&'static str> = Some("usr");
+/// }
+/// pub type UserId = TypedCarettaId<User>;
+///
+/// pub enum Note {}
+/// impl CarettaIdKind for Note {
+///     const PREFIX: Option<&'static str> = Some("note");
+/// }
+/// pub type NoteId = TypedCarettaId<Note>;
+///
+/// let user_id: UserId = "usr_123abcd".parse()?;
+/// assert_eq!(user_id.to_string(), "usr_123abcd");
+/// assert_eq!(user_id.id(), "123abcd".parse::<CarettaId>()?);
+///
+/// // Wrong prefix is rejected.
+/// assert_eq!(
+///     "usr_123abcd".parse::<NoteId>(),
+///     Err(Error::InvalidPrefix { expected: "note" })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct TypedCarettaId<K: CarettaIdKind> {
+    id: CarettaId,
+    kind: PhantomData<fn() -> K>,
+}
+
+impl<K: CarettaIdKind> TypedCarettaId<K> {
+    /// The smallest value. See [`CarettaId::NIL`].
+    pub const NIL: Self = Self::new(CarettaId::NIL);
+
+    /// The largest value. See [`CarettaId::MAX`].
+    pub const MAX: Self = Self::new(CarettaId::MAX);
+
+    /// Creates a new typed id from [`CarettaId`].
+    pub const fn new(id: CarettaId) -> Self {
+        Self {
+            id,
+            kind: PhantomData,
+        }
+    }
+
+    /// Returns the inner [`CarettaId`].
+    pub const fn id(self) -> CarettaId {
+        self.id
+    }
+
+    /// Test if the id is nil.
+    pub fn is_nil(&self) -> bool {
+        self.id.is_nil()
+    }
+
+    /// Test if the id is max.
+    pub fn is_max(&self) -> bool {
+        self.id.is_max()
+    }
+
+    /// Converts an unsigned integer to typed id by truncating bits that exceed the valid range.
+    ///
+    /// See [`CarettaId::from_u64_lossy`] for more details.
+    pub const fn from_u64_lossy(int: u64) -> Self {
+        Self::new(CarettaId::from_u64_lossy(int))
+    }
+
+    /// Attempts to convert a [`u64`] to typed id.
+    ///
+    /// See [`CarettaId::from_u64`] for more details.
+    pub const fn from_u64(value: u64) -> Result<Self, Error> {
+        if value <= CarettaId::MAX.to_u64() {
+            Ok(Self::from_u64_lossy(value))
+        } else {
+            Err(Error::ValueOutOfRange(value))
+        }
+    }
+
+    /// Returns an internal 64bit integer value.
+    pub const fn to_u64(self) -> u64 {
+        self.id.to_u64()
+    }
+}
+
+impl<K: CarettaIdKind> Clone for TypedCarettaId<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: CarettaIdKind> Copy for TypedCarettaId<K> {}
+
+impl<K: CarettaIdKind> PartialEq for TypedCarettaId<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<K: CarettaIdKind> Eq for TypedCarettaId<K> {}
+
+impl<K: CarettaIdKind> PartialOrd for TypedCarettaId<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: CarettaIdKind> Ord for TypedCarettaId<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<K: CarettaIdKind> Hash for TypedCarettaId<K> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<K: CarettaIdKind> Debug for TypedCarettaId<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TypedCarettaId")
+            .field(&format_args!("{}", self))
+            .finish()
+    }
+}
+
+impl<K: CarettaIdKind> Display for TypedCarettaId<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match K::PREFIX {
+            Some(prefix) => write!(f, "{}{}{}", prefix, K::SEPARATOR, self.id),
+            None => Display::fmt(&self.id, f),
+        }
+    }
+}
+
+impl<K: CarettaIdKind> FromStr for TypedCarettaId<K> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = match K::PREFIX {
+            Some(prefix) => s
+                .strip_prefix(prefix)
+                .and_then(|x| x.strip_prefix(K::SEPARATOR))
+                .ok_or(Error::InvalidPrefix { expected: prefix })?,
+            None => s,
+        };
+        s.parse().map(Self::new)
+    }
+}
+
+impl<K: CarettaIdKind> From<CarettaId> for TypedCarettaId<K> {
+    fn from(value: CarettaId) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<K: CarettaIdKind> From<TypedCarettaId<K>> for CarettaId {
+    fn from(value: TypedCarettaId<K>) -> Self {
+        value.id
+    }
+}
+
+impl<K: CarettaIdKind> TryFrom<u64> for TypedCarettaId<K> {
+    type Error = Error;
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::from_u64(value)
+    }
+}
+
+impl<K: CarettaIdKind> From<TypedCarettaId<K>> for u64 {
+    fn from(value: TypedCarettaId<K>) -> Self {
+        value.to_u64()
+    }
+}
+
+impl<K: CarettaIdKind> AsRef<CarettaId> for TypedCarettaId<K> {
+    fn as_ref(&self) -> &CarettaId {
+        &self.id
+    }
+}
@@ -0,0 +1,24 @@
+use super::{CarettaIdKind, TypedCarettaId};
+use crate::{CarettaId, CarettaIdProto, Error};
+
+impl<K: CarettaIdKind> From<TypedCarettaId<K>> for CarettaIdProto {
+    fn from(value: TypedCarettaId<K>) -> Self {
+        value.id.into()
+    }
+}
+
+impl<K: CarettaIdKind> TryFrom<CarettaIdProto> for TypedCarettaId<K> {
+    type Error = Error;
+    fn try_from(value: CarettaIdProto) -> Result<Self, Self::Error> {
+        CarettaId::try_from(value).map(Self::new)
+    }
+}
+
+impl<K: CarettaIdKind> TypedCarettaId<K> {
+    /// Converts a [`CarettaIdProto`] to typed id by truncating bits that exceed the valid range.
+    ///
+    /// See [`CarettaId::from_proto_lossy`] for more details.
+    pub fn from_proto_lossy(value: CarettaIdProto) -> Self {
+        Self::new(CarettaId::from_proto_lossy(value))
+    }
+}
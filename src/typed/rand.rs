@@ -0,0 +1,21 @@
+use super::{CarettaIdKind, TypedCarettaId};
+use crate::CarettaId;
+use ::rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+impl<K: CarettaIdKind> Distribution<TypedCarettaId<K>> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TypedCarettaId<K> {
+        TypedCarettaId::new(rng.random())
+    }
+}
+
+impl<K: CarettaIdKind> TypedCarettaId<K> {
+    /// Generate a new random typed id.
+    ///
+    /// See [`CarettaId::random`] for more details.
+    pub fn random() -> Self {
+        Self::new(CarettaId::random())
+    }
+}
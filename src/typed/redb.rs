@@ -0,0 +1,44 @@
+use super::{CarettaIdKind, TypedCarettaId};
+use crate::CarettaId;
+use ::redb::*;
+
+impl<K: CarettaIdKind> Key for TypedCarettaId<K> {
+    fn compare(data1: &[u8], data2: &[u8]) -> std::cmp::Ordering {
+        <CarettaId as Key>::compare(data1, data2)
+    }
+}
+
+/// Stores [`TypedCarettaId`] same as [`CarettaId`].
+///
+/// # Panics
+///
+/// Decoding a stored value larger than [`CarettaId::MAX`] panics, same as [`CarettaId`].
+/// Open the table with [`UncheckedCarettaId`](crate::UncheckedCarettaId) to detect such values without panicking.
+impl<K: CarettaIdKind> Value for TypedCarettaId<K> {
+    type SelfType<'a>
+        = Self
+    where
+        Self: 'a;
+    type AsBytes<'a>
+        = <CarettaId as Value>::AsBytes<'a>
+    where
+        Self: 'a;
+    fn fixed_width() -> Option<usize> {
+        <CarettaId as Value>::fixed_width()
+    }
+    fn from_bytes<'a>(data: &'a [u8]) -> Self::SelfType<'a>
+    where
+        Self: 'a,
+    {
+        Self::new(<CarettaId as Value>::from_bytes(data))
+    }
+    fn as_bytes<'a, 'b: 'a>(value: &'a Self::SelfType<'b>) -> Self::AsBytes<'a>
+    where
+        Self: 'b,
+    {
+        <CarettaId as Value>::as_bytes(&value.id)
+    }
+    fn type_name() -> TypeName {
+        <CarettaId as Value>::type_name()
+    }
+}
@@ -0,0 +1,15 @@
+use super::{CarettaIdKind, TypedCarettaId};
+use crate::CarettaId;
+use rusqlite::{ToSql, types::FromSql};
+
+impl<K: CarettaIdKind> FromSql for TypedCarettaId<K> {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        CarettaId::column_result(value).map(Self::new)
+    }
+}
+
+impl<K: CarettaIdKind> ToSql for TypedCarettaId<K> {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        self.id.to_sql()
+    }
+}
@@ -0,0 +1,46 @@
+use sea_orm::TryFromU64;
+
+use super::{CarettaIdKind, TypedCarettaId};
+use crate::CarettaId;
+
+impl<K: CarettaIdKind> From<TypedCarettaId<K>> for sea_orm::Value {
+    fn from(value: TypedCarettaId<K>) -> Self {
+        value.id.into()
+    }
+}
+
+impl<K: CarettaIdKind> sea_orm::TryGetable for TypedCarettaId<K> {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        index: I,
+    ) -> Result<Self, sea_orm::TryGetError> {
+        <CarettaId as sea_orm::TryGetable>::try_get_by(res, index).map(Self::new)
+    }
+}
+
+impl<K: CarettaIdKind> sea_orm::sea_query::ValueType for TypedCarettaId<K> {
+    fn try_from(v: sea_orm::Value) -> Result<Self, sea_orm::sea_query::ValueTypeErr> {
+        <CarettaId as sea_orm::sea_query::ValueType>::try_from(v).map(Self::new)
+    }
+    fn type_name() -> String {
+        stringify!(TypedCarettaId).to_owned()
+    }
+    fn array_type() -> sea_orm::sea_query::ArrayType {
+        <CarettaId as sea_orm::sea_query::ValueType>::array_type()
+    }
+    fn column_type() -> sea_orm::ColumnType {
+        <CarettaId as sea_orm::sea_query::ValueType>::column_type()
+    }
+}
+
+impl<K: CarettaIdKind> sea_orm::sea_query::Nullable for TypedCarettaId<K> {
+    fn null() -> sea_orm::Value {
+        <CarettaId as sea_orm::sea_query::Nullable>::null()
+    }
+}
+
+impl<K: CarettaIdKind> TryFromU64 for TypedCarettaId<K> {
+    fn try_from_u64(n: u64) -> Result<Self, sea_orm::DbErr> {
+        CarettaId::try_from_u64(n).map(Self::new)
+    }
+}
@@ -0,0 +1,40 @@
+use super::{CarettaIdKind, TypedCarettaId};
+use crate::CarettaId;
+use ::serde::{Deserialize, Serialize, de::Error};
+
+impl<K: CarettaIdKind> Serialize for TypedCarettaId<K> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.id.serialize(serializer)
+        }
+    }
+}
+
+impl<'de, K: CarettaIdKind> Deserialize<'de> for TypedCarettaId<K> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            #[cfg(feature = "std")]
+            {
+                <String as Deserialize>::deserialize(deserializer)?
+                    .parse::<Self>()
+                    .map_err(D::Error::custom)
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                (<&str as Deserialize>::deserialize(deserializer)?)
+                    .parse::<Self>()
+                    .map_err(D::Error::custom)
+            }
+        } else {
+            CarettaId::deserialize(deserializer).map(Self::new)
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use core::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    str::FromStr,
+};
+
+use crate::{CarettaId, Error};
+
+/// Common interface of [`CarettaId`] and the legacy [`CarettaIdS`](crate::CarettaIdS), [`CarettaIdD`](crate::CarettaIdD),
+/// [`CarettaIdT`](crate::CarettaIdT) and [`CarettaIdQ`](crate::CarettaIdQ).
+///
+/// This allows helpers like storage adapters and CLI commands to be written once for every variant,
+/// e.g. during migration from the legacy variants.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(deprecated)]
+/// # use caretta_id::*;
+/// # fn main() -> Result<(), Error> {
+/// fn describe<T: CarettaIdVariant>(s: &str) -> Result<(String, u64), Error> {
+///     let id = T::parse(s)?;
+///     Ok((id.to_string(), id.to_uint().into()))
+/// }
+///
+/// assert_eq!(describe::<CarettaId>("0000001")?, ("0000001".to_string(), 1));
+/// assert_eq!(describe::<CarettaIdD>("000-001")?, ("000-001".to_string(), 1));
+/// assert_eq!(CarettaIdT::BITS, 45);
+/// assert_eq!(<CarettaIdS as CarettaIdVariant>::MAX.to_uint(), 0x7fff);
+/// # Ok(())
+/// # }
+/// ```
+pub trait CarettaIdVariant:
+    Copy
+    + Debug
+    + Display
+    + Eq
+    + Hash
+    + Ord
+    + FromStr<Err = Error>
+    + TryFrom<Self::Uint, Error = Error>
+    + Into<Self::Uint>
+    + 'static
+{
+    /// Unsigned integer type storing the value.
+    type Uint: Copy + Debug + Display + Eq + Hash + Ord + Into<u64> + TryFrom<u64>;
+
+    /// The size of the value in bits.
+    const BITS: u32;
+
+    /// The smallest value.
+    const NIL: Self;
+
+    /// The largest value.
+    const MAX: Self;
+
+    /// Attempts to convert an unsigned integer.
+    ///
+    /// # Error
+    ///
+    /// Returns error if the value is larger than [`MAX`](Self::MAX).
+    fn from_uint(uint: Self::Uint) -> Result<Self, Error>;
+
+    /// Converts an unsigned integer by truncating bits that exceed [`BITS`](Self::BITS).
+    fn from_uint_lossy(uint: Self::Uint) -> Self;
+
+    /// Returns the unsigned integer value.
+    fn to_uint(self) -> Self::Uint;
+
+    /// Parses the textual representation. Same as [`FromStr`].
+    fn parse(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+impl CarettaIdVariant for CarettaId {
+    type Uint = u64;
+
+    const BITS: u32 = CarettaId::BITS;
+
+    const NIL: Self = CarettaId::NIL;
+
+    const MAX: Self = CarettaId::MAX;
+
+    fn from_uint(uint: Self::Uint) -> Result<Self, Error> {
+        Self::from_u64(uint)
+    }
+
+    fn from_uint_lossy(uint: Self::Uint) -> Self {
+        Self::from_u64_lossy(uint)
+    }
+
+    fn to_uint(self) -> Self::Uint {
+        self.to_u64()
+    }
+}
+
+/// Converts an integer to the variant, checking the value against [`CarettaIdVariant::MAX`].
+pub(crate) fn variant_from_u64<T: CarettaIdVariant>(value: u64) -> Result<T, Error> {
+    if value > T::MAX.to_uint().into() {
+        return Err(Error::ValueOutOfRange(value));
+    }
+    T::Uint::try_from(value)
+        .map_err(|_| Error::ValueOutOfRange(value))
+        .and_then(T::from_uint)
+}
@@ -0,0 +1,90 @@
+#![allow(deprecated)]
+
+use caretta_id::*;
+
+fn assert_parse(s: &str, expected: AnyCarettaId, display: &str) {
+    let id: AnyCarettaId = s.parse().unwrap();
+    assert_eq!(id, expected);
+    assert_eq!(id.to_string(), display);
+    assert_eq!(display.parse::<AnyCarettaId>().unwrap(), expected);
+}
+
+#[test]
+fn caretta_id() {
+    let id: CarettaId = "123abcd".parse().unwrap();
+    assert_parse("123abcd", id.into(), "123abcd");
+    assert_parse("123-abcd", id.into(), "123abcd");
+    assert_parse("123_ABCD", id.into(), "123abcd");
+    assert!(!AnyCarettaId::from(id).is_legacy());
+}
+
+#[test]
+fn legacy() {
+    let s: CarettaIdS = "abc".parse().unwrap();
+    let d: CarettaIdD = "abc-def".parse().unwrap();
+    let t: CarettaIdT = "abc-def-ghj".parse().unwrap();
+    let q: CarettaIdQ = "abc-def-ghj-kmn".parse().unwrap();
+    assert_parse("abc", AnyCarettaId::CarettaIdS(s), "abc");
+    assert_parse("abc-def", AnyCarettaId::CarettaIdD(d), "abc-def");
+    assert_parse("abc_def", AnyCarettaId::CarettaIdD(d), "abc-def");
+    assert_parse("abcdef", AnyCarettaId::CarettaIdD(d), "abc-def");
+    assert_parse("abc-def-ghj", AnyCarettaId::CarettaIdT(t), "abc-def-ghj");
+    assert_parse("abcdefghj", AnyCarettaId::CarettaIdT(t), "abc-def-ghj");
+    assert_parse(
+        "abc-def-ghj-kmn",
+        AnyCarettaId::CarettaIdQ(q),
+        "abc-def-ghj-kmn",
+    );
+    assert_parse(
+        "abcdefghjkmn",
+        AnyCarettaId::CarettaIdQ(q),
+        "abc-def-ghj-kmn",
+    );
+    assert!(AnyCarettaId::from(q).is_legacy());
+}
+
+#[test]
+fn invalid() {
+    assert_eq!("".parse::<AnyCarettaId>(), Err(Error::InvalidLength(0)));
+    assert_eq!("abcd".parse::<AnyCarettaId>(), Err(Error::InvalidLength(4)));
+    assert_eq!(
+        "ab-cdef-gh".parse::<AnyCarettaId>(),
+        Err(Error::InvalidLength(8))
+    );
+    assert_eq!(
+        "abc-def-ghj-kmn-pqr".parse::<AnyCarettaId>(),
+        Err(Error::InvalidLength(15))
+    );
+    for (s, character, index) in [
+        ("abc-def-g", '-', 7),
+        ("a-b-c-d-e-f-g", '-', 1),
+        ("1_2_3abcd", '_', 1),
+        ("1234-abc", '-', 4),
+    ] {
+        assert_eq!(
+            s.parse::<AnyCarettaId>(),
+            Err(Error::InvalidCharacter { character, index })
+        );
+    }
+    assert!("abc-de!".parse::<AnyCarettaId>().is_err());
+    assert!("12!abcd".parse::<AnyCarettaId>().is_err());
+}
+
+#[test]
+fn conversion() {
+    let d: CarettaIdD = "zzz-zzz".parse().unwrap();
+    assert_eq!(
+        CarettaId::try_from(AnyCarettaId::from(d)).unwrap().to_u64(),
+        0x3fff_ffff
+    );
+    assert_eq!(
+        CarettaId::try_from(AnyCarettaId::from(CarettaId::MAX)),
+        Ok(CarettaId::MAX)
+    );
+    let t: CarettaIdT = "zzz-zzz-zzz".parse().unwrap();
+    assert_eq!(
+        CarettaId::try_from(AnyCarettaId::from(t)),
+        Err(Error::ValueOutOfRange(t.to_uint()))
+    );
+    assert_eq!(AnyCarettaId::from(t).to_u64(), t.to_uint());
+}
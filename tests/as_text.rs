@@ -0,0 +1,184 @@
+#![allow(deprecated)]
+
+use caretta_id::*;
+
+#[test]
+fn display() {
+    let id: CarettaId = "123abcd".parse().unwrap();
+    assert_eq!(AsText(id).to_string(), "123abcd");
+    assert_eq!(AsText::from(id).into_inner(), id);
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite {
+    use caretta_id::*;
+    use rusqlite::{Connection, types::Value};
+
+    fn query<T: CarettaIdVariant>(conn: &Connection, value: Value) -> rusqlite::Result<AsText<T>> {
+        conn.query_row("SELECT ?1", [value], |row| row.get(0))
+    }
+
+    #[test]
+    fn write_text() {
+        let conn = Connection::open_in_memory().unwrap();
+        let id: CarettaId = "123abcd".parse().unwrap();
+        let (text, kind): (String, String) = conn
+            .query_row("SELECT ?1, typeof(?1)", [AsText(id)], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(text, "123abcd");
+        assert_eq!(kind, "text");
+
+        let d: CarettaIdD = "abc-def".parse().unwrap();
+        let text: String = conn
+            .query_row("SELECT ?1", [AsText(d)], |row| row.get(0))
+            .unwrap();
+        assert_eq!(text, "abc-def");
+    }
+
+    #[test]
+    fn read_mixed() {
+        let conn = Connection::open_in_memory().unwrap();
+        let id: CarettaId = "123abcd".parse().unwrap();
+        let int = id.to_u64() as i64;
+        assert_eq!(
+            query::<CarettaId>(&conn, Value::Text("123abcd".into())).unwrap(),
+            AsText(id)
+        );
+        assert_eq!(
+            query::<CarettaId>(&conn, Value::Text("123-ABCD".into())).unwrap(),
+            AsText(id)
+        );
+        assert_eq!(
+            query::<CarettaId>(&conn, Value::Integer(int)).unwrap(),
+            AsText(id)
+        );
+        assert_eq!(
+            query::<CarettaIdS>(&conn, Value::Integer(0x7fff)).unwrap(),
+            AsText(CarettaIdS::MAX)
+        );
+    }
+
+    #[test]
+    fn column_affinity() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE note (text_id TEXT, any_id)", ())
+            .unwrap();
+        let id: CarettaId = "123abcd".parse().unwrap();
+        conn.execute("INSERT INTO note VALUES (?1, ?1)", [id])
+            .unwrap();
+        let (text_id, any_id) = conn
+            .query_row("SELECT text_id, any_id FROM note", (), |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, AsText<CarettaId>>(1)?,
+                ))
+            })
+            .unwrap();
+        // Integer is converted to decimal text in the `TEXT` column.
+        assert_eq!(text_id, id.to_u64().to_string());
+        assert!(
+            conn.query_row("SELECT text_id FROM note", (), |row| row
+                .get::<_, AsText<CarettaId>>(0))
+                .is_err()
+        );
+        assert_eq!(any_id, AsText(id));
+    }
+
+    #[test]
+    fn read_invalid() {
+        let conn = Connection::open_in_memory().unwrap();
+        assert!(query::<CarettaId>(&conn, Value::Text("123abc!".into())).is_err());
+        assert!(matches!(
+            query::<CarettaId>(&conn, Value::Integer(CarettaId::MAX.to_u64() as i64 + 1)),
+            Err(rusqlite::Error::IntegralValueOutOfRange(0, x)) if x == CarettaId::MAX.to_u64() as i64 + 1
+        ));
+        assert!(query::<CarettaId>(&conn, Value::Integer(-1)).is_err());
+        assert!(query::<CarettaIdS>(&conn, Value::Integer(0x8000)).is_err());
+        assert!(query::<CarettaId>(&conn, Value::Real(1.0)).is_err());
+        assert!(query::<CarettaId>(&conn, Value::Null).is_err());
+    }
+}
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm {
+    use caretta_id::*;
+    use sea_orm::{
+        DatabaseBackend, MockDatabase, MockExecResult, Transaction,
+        entity::{prelude::*, *},
+        sea_query::ValueType,
+    };
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "note")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: AsText<CarettaId>,
+        pub parent: Option<AsText<CarettaId>>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    #[tokio::test]
+    async fn model() {
+        let id = AsText("123abcd".parse::<CarettaId>().unwrap());
+        let parent = AsText(CarettaId::MAX);
+        let model = Model {
+            id,
+            parent: Some(parent),
+        };
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_query_results([vec![model.clone()], vec![Model { id, parent: None }]])
+            .append_query_results([[std::collections::BTreeMap::from([
+                ("id", Value::BigUnsigned(Some(id.0.to_u64()))),
+                (
+                    "parent",
+                    Value::String(Some(Box::new("zzzzzzz".to_owned()))),
+                ),
+            ])]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let active_model = model.clone().into_active_model().reset_all();
+        assert_eq!(active_model.insert(&db).await.unwrap(), model);
+        assert_eq!(
+            Entity::find().one(&db).await.unwrap(),
+            Some(Model { id, parent: None })
+        );
+        assert_eq!(Entity::find().one(&db).await.unwrap(), Some(model));
+        assert_eq!(
+            db.into_transaction_log()[0],
+            Transaction::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                r#"INSERT INTO "note" ("id", "parent") VALUES (?, ?)"#,
+                ["123abcd".into(), "zzzzzzz".into()]
+            )
+        );
+    }
+
+    #[test]
+    fn value_type() {
+        let id: CarettaId = "123abcd".parse().unwrap();
+        assert_eq!(
+            <AsText<CarettaId> as ValueType>::try_from("123-abcd".into()).unwrap(),
+            AsText(id)
+        );
+        assert_eq!(
+            <AsText<CarettaId> as ValueType>::try_from(id.to_u64().into()).unwrap(),
+            AsText(id)
+        );
+        assert!(<AsText<CarettaId> as ValueType>::try_from("!".into()).is_err());
+        assert!(<AsText<CarettaId> as ValueType>::try_from(u64::MAX.into()).is_err());
+        assert_eq!(
+            <AsText<CarettaId> as ValueType>::column_type(),
+            ColumnType::Text
+        );
+    }
+}
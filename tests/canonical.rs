@@ -0,0 +1,74 @@
+use caretta_id::*;
+
+#[test]
+fn strict() {
+    for id in [CarettaId::NIL, CarettaId::MAX, "123abcd".parse().unwrap()] {
+        assert_eq!(CarettaId::parse_strict(&id.to_string()).unwrap(), id);
+        assert!(CarettaId::is_canonical(&id.to_string()));
+        assert!(!CarettaId::is_canonical(&format!("{id:#}")));
+    }
+    for (alias, canonical) in [
+        ('i', '1'),
+        ('l', '1'),
+        ('o', '0'),
+        ('u', 'v'),
+        ('I', '1'),
+        ('A', 'a'),
+    ] {
+        let s = format!("123abc{alias}");
+        assert_eq!(
+            CarettaId::parse_strict(&s),
+            Err(Error::NonCanonicalCharacter {
+                character: alias,
+                index: 6,
+                canonical
+            })
+        );
+        assert!(!CarettaId::is_canonical(&s));
+    }
+    assert_eq!(
+        CarettaId::parse_strict("123abc"),
+        Err(Error::InvalidLength(6))
+    );
+    assert_eq!(
+        CarettaId::parse_strict("123abcdd"),
+        Err(Error::InvalidLength(8))
+    );
+    assert_eq!(
+        CarettaId::parse_strict("１２３abcd"),
+        Err(Error::InvalidCharacter {
+            character: '１',
+            index: 0
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn canonicalize() {
+    let result = CarettaId::canonicalize("123abcd").unwrap();
+    assert!(result.is_canonical());
+    assert!(result.rewritten().is_empty());
+    assert_eq!(result.id(), "123abcd".parse().unwrap());
+
+    let result = CarettaId::canonicalize("123-ABCD").unwrap();
+    assert_eq!(result.canonical(), "123abcd");
+    assert_eq!(result.rewritten(), &[3, 4, 5, 6, 7]);
+
+    let result = CarettaId::canonicalize("0_000_000").unwrap();
+    assert_eq!(result.id(), CarettaId::NIL);
+    assert_eq!(result.rewritten(), &[1, 5]);
+
+    assert_eq!(
+        CarettaId::canonicalize("_0000000"),
+        Err(Error::InvalidCharacter {
+            character: '_',
+            index: 0
+        })
+    );
+
+    assert_eq!(
+        CarettaId::canonicalize("123abc"),
+        Err(Error::InvalidLength(6))
+    );
+}
@@ -0,0 +1,79 @@
+#![cfg(all(feature = "std", feature = "rand"))]
+
+use caretta_id::{alphabet::ENCODE_TABLE, *};
+
+fn assert_checked(id: CarettaId) {
+    let s = id.checked().to_string();
+    assert_eq!(s.parse::<CheckedCarettaId>().unwrap().id(), id);
+
+    let chars: Vec<char> = s.chars().collect();
+    for i in 0..chars.len() {
+        for c in ENCODE_TABLE.iter().filter(|c| **c != chars[i]) {
+            let mut substituted = chars.clone();
+            substituted[i] = *c;
+            let substituted: String = substituted.into_iter().collect();
+            assert!(matches!(
+                substituted.parse::<CheckedCarettaId>(),
+                Err(Error::ChecksumMismatch { .. })
+            ));
+        }
+    }
+    for i in 0..chars.len() - 1 {
+        if chars[i] != chars[i + 1] {
+            let mut transposed = chars.clone();
+            transposed.swap(i, i + 1);
+            let transposed: String = transposed.into_iter().collect();
+            assert!(matches!(
+                transposed.parse::<CheckedCarettaId>(),
+                Err(Error::ChecksumMismatch { .. })
+            ));
+        }
+    }
+}
+
+#[test]
+fn nil() {
+    assert_checked(CarettaId::NIL);
+}
+
+#[test]
+fn max() {
+    assert_checked(CarettaId::MAX);
+}
+
+#[test]
+fn random() {
+    for _ in 0..100 {
+        assert_checked(CarettaId::random());
+    }
+}
+
+#[test]
+fn invalid_length() {
+    assert_eq!(
+        "123abcd".parse::<CheckedCarettaId>(),
+        Err(Error::InvalidCheckedLength(7))
+    );
+    assert_eq!(
+        "123abcdé".parse::<CheckedCarettaId>(),
+        Err(Error::InvalidCharacter {
+            character: 'é',
+            index: 7
+        })
+    );
+}
+
+#[test]
+fn delimiter() {
+    assert_eq!(
+        "123-abc3".parse::<CheckedCarettaId>(),
+        Err(Error::InvalidCharacter {
+            character: '-',
+            index: 3
+        })
+    );
+    assert_eq!(
+        "123-abcd3".parse::<CheckedCarettaId>(),
+        Err(Error::InvalidCheckedLength(9))
+    );
+}
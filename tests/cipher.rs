@@ -0,0 +1,39 @@
+use caretta_id::*;
+
+const CIPHER: CarettaIdCipher = CarettaIdCipher::new(*b"0123456789abcdef");
+
+fn assert_round_trip(value: u64) {
+    let id = CIPHER.encrypt(value).unwrap();
+    assert_eq!(CIPHER.decrypt(id), value);
+}
+
+#[test]
+fn boundary_value() {
+    assert_round_trip(0);
+    assert_round_trip(CarettaId::MAX.to_u64());
+    assert_eq!(
+        CIPHER.encrypt(CarettaId::MAX.to_u64() + 1),
+        Err(Error::ValueOutOfRange(CarettaId::MAX.to_u64() + 1))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sequential() {
+    let mut ids = std::collections::HashSet::new();
+    for value in 0..10000 {
+        assert_round_trip(value);
+        assert!(ids.insert(CIPHER.encrypt(value).unwrap()));
+    }
+}
+
+#[test]
+fn decrypt_any_id() {
+    for id in [
+        CarettaId::NIL,
+        CarettaId::MAX,
+        CarettaId::from_u64_lossy(0x123456789),
+    ] {
+        assert_eq!(CIPHER.encrypt(CIPHER.decrypt(id)).unwrap(), id);
+    }
+}
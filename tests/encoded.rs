@@ -0,0 +1,50 @@
+use caretta_id::*;
+
+#[test]
+fn encode() {
+    for id in [CarettaId::NIL, CarettaId::MAX, "123abcd".parse().unwrap()] {
+        let encoded = id.encode();
+        assert_eq!(encoded.as_str(), id.to_string());
+        assert_eq!(encoded.as_str().parse::<CarettaId>().unwrap(), id);
+        assert_eq!(EncodedCarettaId::from(id), encoded);
+    }
+}
+
+#[test]
+fn alias_is_canonicalized() {
+    let id: CarettaId = "OL2ATUW".parse().unwrap();
+    assert_eq!(id.encode(), "012atvw");
+}
+
+#[test]
+fn encode_to_slice() {
+    let id: CarettaId = "123abcd".parse().unwrap();
+    let mut buf = [0; 10];
+    assert_eq!(id.encode_to_slice(&mut buf), "123abcd");
+    assert_eq!(&buf, b"123abcd\0\0\0");
+}
+
+#[test]
+#[should_panic]
+fn encode_to_short_slice() {
+    CarettaId::NIL.encode_to_slice(&mut [0; 6]);
+}
+
+#[test]
+fn format() {
+    let encoded = CarettaId::NIL.encode();
+    assert_eq!(format!("{encoded}"), "0000000");
+    assert_eq!(format!("{encoded:>9}"), "  0000000");
+    assert_eq!(format!("{encoded:?}"), "\"0000000\"");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn borrow() {
+    use std::collections::HashSet;
+
+    let set: HashSet<EncodedCarettaId> = [CarettaId::NIL.encode(), CarettaId::MAX.encode()].into();
+    assert!(set.contains("0000000"));
+    assert!(set.contains("zzzzzzz"));
+    assert!(!set.contains("123abcd"));
+}
@@ -0,0 +1,100 @@
+use caretta_id::*;
+
+fn id() -> CarettaId {
+    "123abcd".parse().unwrap()
+}
+
+#[test]
+fn flags() {
+    let id = id();
+    assert_eq!(format!("{id}"), "123abcd");
+    assert_eq!(format!("{id:#}"), "123-abcd");
+    assert_eq!(format!("{id:X}"), "123ABCD");
+    assert_eq!(format!("{id:#X}"), "123-ABCD");
+}
+
+#[test]
+fn display_with() {
+    let id = id();
+    assert_eq!(
+        id.display_with(CarettaIdFormat::PLAIN).to_string(),
+        "123abcd"
+    );
+    assert_eq!(
+        id.display_with(CarettaIdFormat::GROUPED.separator('_'))
+            .to_string(),
+        "123_abcd"
+    );
+    assert_eq!(
+        id.display_with(CarettaIdFormat::new().groups(&[1; 7]).separator(' '))
+            .to_string(),
+        "1 2 3 a b c d"
+    );
+    assert_eq!(
+        id.display_with(CarettaIdFormat::new().groups(&[0, 2, 0, 10]))
+            .to_string(),
+        "12-3abcd"
+    );
+    assert_eq!(
+        id.display_with(CarettaIdFormat::GROUPED.groups(&[]))
+            .to_string(),
+        "123abcd"
+    );
+    assert_eq!(
+        id.display_with(CarettaIdFormat::new().groups(&[4]).separator('·'))
+            .to_string(),
+        "123a·bcd"
+    );
+}
+
+#[test]
+fn round_trip() {
+    for id in [CarettaId::NIL, CarettaId::MAX, id()] {
+        for s in [
+            format!("{id:#}"),
+            format!("{id:X}"),
+            format!("{id:#X}"),
+            id.display_with(CarettaIdFormat::new().groups(&[2, 2, 2]).separator('_'))
+                .to_string(),
+        ] {
+            assert_eq!(s.parse::<CarettaId>().unwrap(), id);
+        }
+    }
+}
+
+#[test]
+fn parse_delimited() {
+    assert_eq!("1-2-3-a-b-c-d".parse::<CarettaId>().unwrap(), id());
+    assert_eq!("12_3ab_cd".parse::<CarettaId>().unwrap(), id());
+    for (s, character, index) in [
+        ("-123abcd", '-', 0),
+        ("123abcd_", '_', 7),
+        ("123--abcd", '-', 4),
+        ("123-ab_cd", '_', 6),
+        ("-------", '-', 0),
+    ] {
+        assert_eq!(
+            s.parse::<CarettaId>(),
+            Err(Error::InvalidCharacter { character, index })
+        );
+        assert_eq!(
+            CarettaId::parse_ascii(s.as_bytes()),
+            Err(Error::InvalidByte {
+                byte: character as u8,
+                position: index
+            })
+        );
+    }
+    assert_eq!(
+        "123 abcd".parse::<CarettaId>(),
+        Err(Error::InvalidCharacter {
+            character: ' ',
+            index: 3
+        })
+    );
+    assert_eq!("123-abc".parse::<CarettaId>(), Err(Error::InvalidLength(6)));
+    assert_eq!(
+        CarettaId::parse_ascii(b"123-abc"),
+        Err(Error::InvalidLength(6))
+    );
+}
@@ -0,0 +1,64 @@
+#![cfg(all(feature = "std", feature = "rand"))]
+
+use caretta_id::*;
+
+#[test]
+fn abbreviations_resolve() {
+    let index: CarettaIdIndex = (0..1000).map(|_| CarettaId::random()).collect();
+    for (id, abbreviation) in index.abbreviations(0) {
+        assert_eq!(index.resolve(&abbreviation).unwrap(), id);
+        if abbreviation.len() > 1 {
+            let shorter = &abbreviation[..abbreviation.len() - 1];
+            let candidates = index.candidates(shorter).unwrap().to_vec();
+            assert!(candidates.len() > 1);
+            assert!(candidates.contains(&id));
+            assert_eq!(
+                index.resolve(shorter),
+                Err(ResolveError::Ambiguous(candidates))
+            );
+        }
+    }
+}
+
+#[test]
+fn resolve_ambiguous() {
+    let a: CarettaId = "123abcd".parse().unwrap();
+    let b: CarettaId = "123azzz".parse().unwrap();
+    let index = CarettaIdIndex::from_iter([b, "456wxyz".parse().unwrap(), a]);
+    assert_eq!(
+        index.resolve("123"),
+        Err(ResolveError::Ambiguous(vec![a, b]))
+    );
+    assert_eq!(index.resolve("789"), Err(ResolveError::NotFound));
+}
+
+#[test]
+fn resolve_full_id() {
+    let id = CarettaId::random();
+    let index = CarettaIdIndex::from_iter([id, CarettaId::NIL, CarettaId::MAX]);
+    assert_eq!(index.resolve(&id.to_string()).unwrap(), id);
+}
+
+#[test]
+fn resolve_alias() {
+    let id: CarettaId = "012abcd".parse().unwrap();
+    let index = CarettaIdIndex::from_iter([id]);
+    assert_eq!(index.resolve("OL2A").unwrap(), id);
+}
+
+#[test]
+fn invalid_prefix() {
+    let index = CarettaIdIndex::from_iter([CarettaId::NIL]);
+    assert_eq!(index.resolve(""), Err(Error::InvalidLength(0).into()));
+    assert_eq!(
+        index.resolve("00000000"),
+        Err(Error::InvalidLength(8).into())
+    );
+    assert_eq!(
+        index.resolve("0u!"),
+        Err(ResolveError::Invalid(Error::InvalidCharacter {
+            character: '!',
+            index: 2
+        }))
+    );
+}
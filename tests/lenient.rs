@@ -0,0 +1,72 @@
+use caretta_id::*;
+
+fn id() -> CarettaId {
+    "123abcd".parse().unwrap()
+}
+
+#[test]
+fn same_as_from_str() {
+    for s in ["123abcd", "123-ABCD", "l23_abcd", "OOOOOOO", "zzzzzzz"] {
+        assert_eq!(CarettaId::parse_lenient(s), s.parse::<CarettaId>());
+    }
+}
+
+#[test]
+fn full_width() {
+    assert_eq!(CarettaId::parse_lenient("１２３ａｂｃｄ").unwrap(), id());
+    assert_eq!(CarettaId::parse_lenient("１２３－ＡＢＣＤ").unwrap(), id());
+    assert_eq!(CarettaId::parse_lenient("１２３　ａｂｃｄ").unwrap(), id());
+    assert_eq!(CarettaId::parse_lenient("１２３ーａｂｃｄ").unwrap(), id());
+    assert_eq!(
+        CarettaId::parse_lenient("ＯＬ２ＡＴＵＷ").unwrap(),
+        "012atvw".parse().unwrap()
+    );
+}
+
+#[test]
+fn homoglyphs() {
+    // Cyrillic
+    assert_eq!(CarettaId::parse_lenient("123аВсd").unwrap(), id());
+    assert_eq!(CarettaId::parse_lenient("оОоОоОо").unwrap(), CarettaId::NIL);
+    // Greek
+    assert_eq!(CarettaId::parse_lenient("123ΑΒcd").unwrap(), id());
+    assert_eq!(CarettaId::parse_lenient("ΟΟΟΟΟΟΟ").unwrap(), CarettaId::NIL);
+}
+
+#[test]
+fn invisible() {
+    assert_eq!(
+        CarettaId::parse_lenient("\u{feff}123\u{200b}abcd\u{200d}").unwrap(),
+        id()
+    );
+    assert_eq!(CarettaId::parse_lenient("\t123\u{a0}abcd\n").unwrap(), id());
+    assert_eq!(CarettaId::parse_lenient("123‐abcd").unwrap(), id());
+    assert_eq!(CarettaId::parse_lenient("123−abcd").unwrap(), id());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(CarettaId::parse_lenient(""), Err(Error::InvalidLength(0)));
+    assert_eq!(
+        CarettaId::parse_lenient("１２３ａｂｃｄｅ"),
+        Err(Error::InvalidLength(8))
+    );
+    assert_eq!(
+        CarettaId::parse_lenient("１２３ａｂｃ"),
+        Err(Error::InvalidLength(6))
+    );
+    assert_eq!(
+        CarettaId::parse_lenient("１２３ａ日ｃｄ"),
+        Err(Error::InvalidCharacter {
+            character: '日',
+            index: 4
+        })
+    );
+    assert_eq!(
+        CarettaId::parse_lenient(" ä23abcd"),
+        Err(Error::InvalidCharacter {
+            character: 'ä',
+            index: 1
+        })
+    );
+}
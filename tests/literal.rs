@@ -0,0 +1,48 @@
+use caretta_id::*;
+
+const NIL: CarettaId = caretta_id!("0000000");
+const ALIAS: CarettaId = caretta_id!("OL2ATUW");
+static MAX: CarettaId = caretta_id!("zzzzzzz");
+
+#[test]
+fn literal() {
+    assert_eq!(NIL, CarettaId::NIL);
+    assert_eq!(MAX, CarettaId::MAX);
+    assert_eq!(ALIAS, "012atvw".parse().unwrap());
+}
+
+#[test]
+fn try_parse() {
+    const VALID: Result<CarettaId, Error> = CarettaId::try_parse("123abcd");
+    assert_eq!(VALID, "123abcd".parse());
+    assert_eq!(CarettaId::try_parse(""), Err(Error::InvalidLength(0)));
+    assert_eq!(
+        CarettaId::try_parse("123abcde"),
+        Err(Error::InvalidLength(8))
+    );
+}
+
+#[test]
+fn non_ascii() {
+    assert_eq!(
+        CarettaId::try_parse("12ä3ab"),
+        Err(Error::InvalidCharacter {
+            character: 'ä',
+            index: 2
+        })
+    );
+    assert_eq!(
+        "1234€".parse::<CarettaId>(),
+        Err(Error::InvalidCharacter {
+            character: '€',
+            index: 4
+        })
+    );
+    assert_eq!(
+        "123😀".parse::<CarettaId>(),
+        Err(Error::InvalidCharacter {
+            character: '😀',
+            index: 3
+        })
+    );
+}
@@ -0,0 +1,212 @@
+#![allow(deprecated)]
+
+use caretta_id::*;
+
+#[test]
+fn checked() {
+    let id = CarettaId::from(CarettaIdD::MAX);
+    assert_eq!(id.to_u64(), 0x3fff_ffff);
+    assert_eq!(CarettaIdD::try_from(id).unwrap(), CarettaIdD::MAX);
+    assert_eq!(CarettaId::from(CarettaIdS::NIL), CarettaId::NIL);
+    assert_eq!(
+        CarettaId::try_from(CarettaIdT::from_uint(CarettaId::MAX.to_u64()).unwrap()).unwrap(),
+        CarettaId::MAX
+    );
+    assert_eq!(
+        CarettaId::try_from(CarettaIdQ::MAX),
+        Err(Error::ValueOutOfRange(CarettaIdQ::MAX.to_uint()))
+    );
+    assert_eq!(
+        CarettaIdS::try_from(CarettaId::MAX),
+        Err(Error::ValueOutOfRange(CarettaId::MAX.to_u64()))
+    );
+    assert_eq!(
+        CarettaIdQ::from(CarettaId::MAX).to_uint(),
+        CarettaId::MAX.to_u64()
+    );
+}
+
+#[test]
+fn lossy() {
+    let capacity = CarettaId::MAX.to_u64() + 1;
+    assert_eq!(
+        CarettaId::from_variant_lossy(CarettaIdT::MAX),
+        CarettaId::MAX
+    );
+    assert_eq!(
+        CarettaId::from_variant_lossy(CarettaIdQ::from_uint(capacity * 3 + 5).unwrap()),
+        CarettaId::from_u64(5).unwrap()
+    );
+    assert_eq!(
+        CarettaId::MAX.into_variant_lossy::<CarettaIdD>(),
+        CarettaIdD::MAX
+    );
+    assert_eq!(
+        CarettaId::from_u64(0x8001)
+            .unwrap()
+            .into_variant_lossy::<CarettaIdS>(),
+        CarettaIdS::from_uint(1).unwrap()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn remap() {
+    let capacity = CarettaId::MAX.to_u64() + 1;
+    let t = |x: u64| CarettaIdT::from_uint(x).unwrap();
+    let old = [
+        t(capacity + 1),
+        t(1),
+        t(capacity * 2 + 1),
+        t(capacity + 2),
+        t(1),
+        t(capacity - 1),
+        t(capacity * 2 - 1),
+    ];
+    let remap: CarettaIdRemap<_> = old.into_iter().collect();
+    let id = |x: u64| CarettaId::from_u64(x).unwrap();
+
+    assert_eq!(remap.len(), 6);
+    assert_eq!(remap.get(&t(1)), Some(id(1)));
+    assert_eq!(remap.get(&t(capacity - 1)), Some(id(capacity - 1)));
+    assert_eq!(remap.get(&t(capacity + 1)), Some(id(2)));
+    assert_eq!(remap.get(&t(capacity + 2)), Some(id(3)));
+    assert_eq!(remap.get(&t(capacity * 2 - 1)), Some(id(0)));
+    assert_eq!(remap.get(&t(capacity * 2 + 1)), Some(id(4)));
+    assert_eq!(remap.get(&t(2)), None);
+    assert_eq!(
+        remap.collisions(),
+        &[
+            t(capacity + 1),
+            t(capacity + 2),
+            t(capacity * 2 - 1),
+            t(capacity * 2 + 1)
+        ]
+    );
+    assert!(!remap.is_identity());
+
+    let new: std::collections::BTreeSet<_> = remap.iter().map(|(_, new)| *new).collect();
+    assert_eq!(new.len(), remap.len());
+
+    let remap = CarettaIdRemap::new([CarettaIdS::MAX, CarettaIdS::NIL]);
+    assert!(remap.is_identity());
+    assert!(remap.collisions().is_empty());
+    assert!(CarettaIdRemap::<CarettaIdQ>::new([]).is_empty());
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite() {
+    let capacity = CarettaId::MAX.to_u64() + 1;
+    let t = |x: u64| CarettaIdT::from_uint(x).unwrap();
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute(
+        r#"CREATE TABLE "legacy note" (body TEXT, "id column" INTEGER UNIQUE, parent INTEGER)"#,
+        (),
+    )
+    .unwrap();
+    let old = [t(1), t(capacity + 1), t(capacity * 2 + 7)];
+    for (i, id) in old.iter().enumerate() {
+        conn.execute(
+            r#"INSERT INTO "legacy note" (body, "id column") VALUES (?1, ?2)"#,
+            rusqlite::params![i, id],
+        )
+        .unwrap();
+    }
+    conn.execute(r#"INSERT INTO "legacy note" (body) VALUES ('null')"#, ())
+        .unwrap();
+
+    let column = CarettaIdColumn::new(&conn, "legacy note", "id column");
+    let remap = column.migrate::<CarettaIdT>().unwrap();
+    assert_eq!(remap.len(), 3);
+    for (i, id) in old.iter().enumerate() {
+        let new: CarettaId = conn
+            .query_row(
+                r#"SELECT "id column" FROM "legacy note" WHERE body = ?1"#,
+                [i],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(remap.get(id), Some(new));
+    }
+    assert!(column.migrate::<CarettaId>().unwrap().is_identity());
+
+    // Nothing is changed when an id is out of range.
+    conn.execute(
+        r#"INSERT INTO "legacy note" (body, "id column") VALUES ('big', ?1)"#,
+        [CarettaIdQ::MAX],
+    )
+    .unwrap();
+    assert!(matches!(
+        column.migrate::<CarettaIdT>(),
+        Err(MigrateError::Id(Error::ValueOutOfRange(_)))
+    ));
+    let max: u64 = conn
+        .query_row(r#"SELECT MAX("id column") FROM "legacy note""#, (), |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(max, CarettaIdQ::MAX.to_uint());
+}
+
+#[cfg(feature = "redb")]
+#[test]
+fn redb() {
+    let capacity = CarettaId::MAX.to_u64() + 1;
+    use redb::{
+        Database, ReadableDatabase, ReadableTableMetadata, TableDefinition,
+        backends::InMemoryBackend,
+    };
+    const LEGACY: TableDefinition<CarettaIdT, u32> = TableDefinition::new("migrate");
+    const TABLE: TableDefinition<CarettaId, u32> = TableDefinition::new("migrate");
+
+    let t = |x: u64| CarettaIdT::from_uint(x).unwrap();
+    let old = [t(1), t(capacity + 1), t(capacity * 2 + 7)];
+    let database = Database::builder()
+        .create_with_backend(InMemoryBackend::new())
+        .unwrap();
+    let write_txn = database.begin_write().unwrap();
+    {
+        let mut table = write_txn.open_table(LEGACY).unwrap();
+        for (i, id) in old.iter().enumerate() {
+            table.insert(id, i as u32).unwrap();
+        }
+    }
+    write_txn.commit().unwrap();
+
+    let write_txn = database.begin_write().unwrap();
+    let remap = migrate_redb_table::<CarettaIdT, u32>(&write_txn, "migrate").unwrap();
+    write_txn.commit().unwrap();
+
+    let read_txn = database.begin_read().unwrap();
+    let table = read_txn.open_table(TABLE).unwrap();
+    assert_eq!(table.len().unwrap(), 3);
+    for (i, id) in old.iter().enumerate() {
+        let new = remap.get(id).unwrap();
+        assert_eq!(table.get(new).unwrap().unwrap().value(), i as u32);
+    }
+    drop(table);
+    drop(read_txn);
+
+    // The table is no longer keyed by `CarettaIdT`.
+    let write_txn = database.begin_write().unwrap();
+    assert!(matches!(
+        migrate_redb_table::<CarettaIdT, u32>(&write_txn, "migrate"),
+        Err(MigrateError::Store(_))
+    ));
+}
+
+#[cfg(feature = "redb")]
+#[test]
+fn redb_missing_table() {
+    use redb::{Database, backends::InMemoryBackend};
+    let database = Database::builder()
+        .create_with_backend(InMemoryBackend::new())
+        .unwrap();
+    let write_txn = database.begin_write().unwrap();
+    assert!(matches!(
+        migrate_redb_table::<CarettaIdT, u32>(&write_txn, "missing"),
+        Err(MigrateError::Store(redb::Error::TableDoesNotExist(name))) if name == "missing"
+    ));
+    assert_eq!(write_txn.list_tables().unwrap().count(), 0);
+}
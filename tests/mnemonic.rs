@@ -0,0 +1,99 @@
+use caretta_id::*;
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            current.push(
+                (previous[j + 1] + 1)
+                    .min(current[j] + 1)
+                    .min(previous[j] + usize::from(ca != *cb)),
+            );
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[test]
+fn word_list() {
+    let words = CarettaIdMnemonic::WORDS;
+    assert!(words.is_sorted());
+    for (i, a) in words.iter().enumerate() {
+        assert!(a.bytes().all(|x| x.is_ascii_lowercase()));
+        for b in &words[i + 1..] {
+            assert!(levenshtein(a, b) >= 3, "{a} and {b} are too similar");
+        }
+    }
+}
+
+#[test]
+fn vectors() {
+    for (id, mnemonic) in [
+        ("0000000", "abbey-abbey-abbey-abbey"),
+        ("012atvw", "abbey-cupcake-falcon-popcorn"),
+        ("123abcd", "airport-kidney-diamond-raccoon"),
+        ("zzzzzzz", "jackal-zodiac-zodiac-zodiac"),
+    ] {
+        let id: CarettaId = id.parse().unwrap();
+        assert_eq!(id.to_mnemonic().to_string(), mnemonic);
+        assert_eq!(CarettaId::from_mnemonic(mnemonic).unwrap(), id);
+    }
+}
+
+#[test]
+fn tolerant() {
+    let id: CarettaId = "123abcd".parse().unwrap();
+    for s in [
+        "AIRPORT KIDNEY DIAMOND RACCOON",
+        "airport, kidney, diamond, raccoon.",
+        "  airport_kidney\tdiamond\nraccoon ",
+        "airpor kidneys diamont raccoon",
+        "irport kidney diamond racoon",
+    ] {
+        assert_eq!(CarettaId::from_mnemonic(s).unwrap(), id, "{s}");
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        CarettaId::from_mnemonic("airport kidney diamond"),
+        Err(Error::InvalidWordCount(3))
+    );
+    assert_eq!(
+        CarettaId::from_mnemonic("airport kidney diamond raccoon abbey"),
+        Err(Error::InvalidWordCount(5))
+    );
+    assert_eq!(
+        CarettaId::from_mnemonic(""),
+        Err(Error::InvalidWordCount(0))
+    );
+    assert_eq!(
+        CarettaId::from_mnemonic("airport kidney diamond racketball"),
+        Err(Error::UnknownWord { index: 3 })
+    );
+    assert_eq!(
+        CarettaId::from_mnemonic("airport kiddo diamond raccoon"),
+        Err(Error::UnknownWord { index: 1 })
+    );
+    assert!(matches!(
+        CarettaId::from_mnemonic("zodiac abbey abbey abbey"),
+        Err(Error::ValueOutOfRange(_))
+    ));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn round_trip() {
+    for _ in 0..1000 {
+        let id = CarettaId::random();
+        let mnemonic = id.to_mnemonic();
+        assert_eq!(
+            mnemonic.to_string().parse::<CarettaIdMnemonic>().unwrap(),
+            mnemonic
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use caretta_id::*;
+
+fn assert_name(namespace: &str, name: &[u8], expected: &str) {
+    let namespace: CarettaId = namespace.parse().unwrap();
+    assert_eq!(CarettaId::from_name(namespace, name).to_string(), expected);
+}
+
+#[test]
+fn vectors() {
+    assert_name("0000000", b"", "ff9h8ks");
+    assert_name("0000000", b"example.com", "b0s83wh");
+    assert_name("123abcd", b"example.com", "f57qjqx");
+    assert_name("123abcd", b"https://example.com/", "25ws3a7");
+    assert_name("zzzzzzz", b"/home/user/notes.md", "q9mc104");
+}
+
+#[test]
+fn const_eval() {
+    const ID: CarettaId = CarettaId::from_name(CarettaId::NIL, b"example.com");
+    assert_eq!(ID.to_string(), "b0s83wh");
+}
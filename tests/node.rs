@@ -0,0 +1,54 @@
+use caretta_id::*;
+
+#[test]
+fn invalid_config() {
+    assert_eq!(NodeGenerator::new(0, 0), Err(Error::InvalidBitWidth(0)));
+    assert_eq!(NodeGenerator::new(35, 0), Err(Error::InvalidBitWidth(35)));
+    assert_eq!(NodeGenerator::new(4, 16), Err(Error::ValueOutOfRange(16)));
+    assert!(NodeGenerator::new(4, 15).is_ok());
+}
+
+#[test]
+fn insufficient_local_bits() {
+    let generator = NodeGenerator::new(34, 0).unwrap();
+    assert_eq!(generator.require_local_bits(1), Ok(generator));
+    assert_eq!(
+        generator.require_local_bits(2),
+        Err(Error::InsufficientLocalBits {
+            required: 2,
+            available: 1
+        })
+    );
+}
+
+#[test]
+fn counter_overflow() {
+    let mut generator = NodeGenerator::new(33, 1).unwrap();
+    for local in 0..4 {
+        let id = generator.next_counter().unwrap();
+        assert_eq!(generator.node_of(id), 1);
+        assert_eq!(generator.local_of(id), local);
+    }
+    assert_eq!(generator.next_counter(), Err(Error::CounterOverflow));
+}
+
+#[test]
+fn resume_counter() {
+    let mut generator = NodeGenerator::new(10, 1023)
+        .unwrap()
+        .with_counter(100)
+        .unwrap();
+    let id = generator.next_counter().unwrap();
+    assert_eq!(generator.local_of(id), 100);
+    assert_eq!(generator.counter(), 101);
+    assert!(NodeGenerator::new(34, 0).unwrap().with_counter(2).is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random() {
+    let generator = NodeGenerator::new(8, 200).unwrap();
+    for _ in 0..10 {
+        assert_eq!(generator.node_of(generator.random()), 200);
+    }
+}
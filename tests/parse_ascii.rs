@@ -0,0 +1,30 @@
+use caretta_id::*;
+
+#[test]
+fn parse_ascii() {
+    assert_eq!(CarettaId::parse_ascii(b"OL2ATUW"), "012atvw".parse());
+    assert_eq!(CarettaId::parse_ascii(b""), Err(Error::InvalidLength(0)));
+    assert_eq!(
+        CarettaId::parse_ascii("12ä3ab".as_bytes()),
+        Err(Error::InvalidByte {
+            byte: 0xc3,
+            position: 2
+        })
+    );
+    assert_eq!(
+        CarettaId::parse_ascii(b"123-ABCD"),
+        Ok("123abcd".parse().unwrap())
+    );
+}
+
+#[test]
+fn try_from_array() {
+    assert_eq!(CarettaId::try_from(b"123abcd"), "123abcd".parse());
+    assert_eq!(
+        CarettaId::try_from(b"123abc\0"),
+        Err(Error::InvalidByte {
+            byte: 0,
+            position: 6
+        })
+    );
+}
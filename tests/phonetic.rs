@@ -0,0 +1,64 @@
+use caretta_id::{alphabet::ENCODE_TABLE, *};
+
+#[test]
+fn all_characters() {
+    for c in ENCODE_TABLE {
+        let s: String = [*c; 7].iter().collect();
+        let id: CarettaId = s.parse().unwrap();
+        let phonetic = id.to_phonetic().to_string();
+        assert_eq!(CarettaId::from_phonetic(&phonetic).unwrap(), id);
+        assert_eq!(
+            CarettaId::from_phonetic(&phonetic.to_uppercase()).unwrap(),
+            id
+        );
+    }
+}
+
+#[test]
+fn aliases() {
+    assert_eq!(
+        CarettaId::from_phonetic("oscar lima india uniform zero one victor").unwrap(),
+        "011v01v".parse().unwrap()
+    );
+    assert_eq!(
+        CarettaId::from_phonetic("tree fife niner alpha juliet xray whisky").unwrap(),
+        "359ajxw".parse().unwrap()
+    );
+}
+
+#[test]
+fn words() {
+    let id: CarettaId = "09xz5kw".parse().unwrap();
+    assert_eq!(
+        id.to_phonetic().words(),
+        ["zero", "nine", "x-ray", "zulu", "five", "kilo", "whiskey"]
+    );
+    assert_eq!(
+        id.to_phonetic()
+            .to_string()
+            .parse::<CarettaIdPhonetic>()
+            .unwrap()
+            .id(),
+        id
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        CarettaId::from_phonetic("one two three alfa bravo charlie"),
+        Err(Error::InvalidLength(6))
+    );
+    assert_eq!(
+        CarettaId::from_phonetic("one two three alfa bravo charlie delta echo"),
+        Err(Error::InvalidLength(8))
+    );
+    assert_eq!(
+        CarettaId::from_phonetic("one two three apple bravo charlie delta"),
+        Err(Error::UnknownWord { index: 3 })
+    );
+    assert_eq!(
+        CarettaId::from_phonetic("one two three ! bravo charlie delta"),
+        Err(Error::UnknownWord { index: 3 })
+    );
+}
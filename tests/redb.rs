@@ -45,3 +45,92 @@ fn random() {
         assert_insert(<CarettaId>::random(), <CarettaId>::random());
     }
 }
+
+/// Raw integer stored with the type name of [`CarettaId`], used to simulate a corrupted value.
+#[derive(Debug)]
+struct RawCarettaId;
+
+impl redb::Value for RawCarettaId {
+    type SelfType<'a> = u64;
+    type AsBytes<'a> = [u8; 8];
+    fn fixed_width() -> Option<usize> {
+        Some(8)
+    }
+    fn from_bytes<'a>(data: &'a [u8]) -> u64
+    where
+        Self: 'a,
+    {
+        u64::from_le_bytes(data.try_into().unwrap())
+    }
+    fn as_bytes<'a, 'b: 'a>(value: &'a u64) -> [u8; 8]
+    where
+        Self: 'b,
+    {
+        value.to_le_bytes()
+    }
+    fn type_name() -> redb::TypeName {
+        <CarettaId as redb::Value>::type_name()
+    }
+}
+
+fn insert_oversized(name: &str) -> Database {
+    let database = Database::builder()
+        .create_with_backend(InMemoryBackend::new())
+        .unwrap();
+    let write_txn = database.begin_write().unwrap();
+    write_txn
+        .open_table(TableDefinition::<u32, RawCarettaId>::new(name))
+        .unwrap()
+        .insert(0, CarettaId::MAX.to_u64() + 2)
+        .unwrap();
+    write_txn.commit().unwrap();
+    database
+}
+
+#[test]
+#[should_panic(expected = "Failed to decode CarettaId from redb")]
+fn out_of_range() {
+    let database = insert_oversized("out_of_range");
+    let read_txn = database.begin_read().unwrap();
+    let table = read_txn
+        .open_table(TableDefinition::<u32, CarettaId>::new("out_of_range"))
+        .unwrap();
+    table.get(0).unwrap().unwrap().value();
+}
+
+#[test]
+fn out_of_range_lossy() {
+    use caretta_id::LossyCarettaId;
+    let database = insert_oversized("out_of_range_lossy");
+    let read_txn = database.begin_read().unwrap();
+    let table = read_txn
+        .open_table(TableDefinition::<u32, LossyCarettaId>::new(
+            "out_of_range_lossy",
+        ))
+        .unwrap();
+    assert_eq!(
+        table.get(0).unwrap().unwrap().value(),
+        LossyCarettaId(CarettaId::from_u64_lossy(CarettaId::MAX.to_u64() + 2))
+    );
+}
+
+#[test]
+fn out_of_range_unchecked() {
+    use caretta_id::{Error, UncheckedCarettaId};
+    let database = insert_oversized("out_of_range_unchecked");
+    let read_txn = database.begin_read().unwrap();
+    let table = read_txn
+        .open_table(TableDefinition::<u32, UncheckedCarettaId>::new(
+            "out_of_range_unchecked",
+        ))
+        .unwrap();
+    let value = table.get(0).unwrap().unwrap().value();
+    assert_eq!(value.to_u64(), CarettaId::MAX.to_u64() + 2);
+    assert_eq!(
+        value.check(),
+        Err(Error::ValueOutOfRange(CarettaId::MAX.to_u64() + 2))
+    );
+
+    let id = CarettaId::MAX;
+    assert_eq!(UncheckedCarettaId::from(id).check(), Ok(id));
+}
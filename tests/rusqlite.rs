@@ -0,0 +1,36 @@
+#![cfg(feature = "rusqlite")]
+
+use caretta_id::{CarettaId, LossyCarettaId};
+use rusqlite::Connection;
+
+fn query<T: rusqlite::types::FromSql>(conn: &Connection, value: i64) -> rusqlite::Result<T> {
+    conn.query_row("SELECT ?1", [value], |row| row.get(0))
+}
+
+#[test]
+fn roundtrip() {
+    let conn = Connection::open_in_memory().unwrap();
+    for id in [CarettaId::NIL, CarettaId::MAX] {
+        let stored: CarettaId = conn.query_row("SELECT ?1", [id], |row| row.get(0)).unwrap();
+        assert_eq!(stored, id);
+        let stored: LossyCarettaId = conn
+            .query_row("SELECT ?1", [LossyCarettaId(id)], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, LossyCarettaId(id));
+    }
+}
+
+#[test]
+fn out_of_range() {
+    let conn = Connection::open_in_memory().unwrap();
+    let oversized = CarettaId::MAX.to_u64() as i64 + 2;
+    assert!(matches!(
+        query::<CarettaId>(&conn, oversized),
+        Err(rusqlite::Error::IntegralValueOutOfRange(0, x)) if x == oversized
+    ));
+    assert!(query::<CarettaId>(&conn, -1).is_err());
+    assert_eq!(
+        query::<LossyCarettaId>(&conn, oversized).unwrap(),
+        LossyCarettaId(CarettaId::from_u64_lossy(oversized as u64))
+    );
+}
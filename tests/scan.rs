@@ -0,0 +1,74 @@
+use caretta_id::*;
+
+fn find_all(scanner: CarettaIdScanner, text: &str) -> Vec<(&str, std::ops::Range<usize>)> {
+    scanner
+        .find_iter(text)
+        .map(|m| (m.as_str(), m.range()))
+        .collect()
+}
+
+#[test]
+fn word_boundary() {
+    let scanner = CarettaIdScanner::new();
+    assert_eq!(find_all(scanner, "123abcd"), vec![("123abcd", 0..7)]);
+    assert_eq!(
+        find_all(scanner, "(123abcd) 456wxyz."),
+        vec![("123abcd", 1..8), ("456wxyz", 10..17)]
+    );
+    assert!(find_all(scanner, "x123abcd 123abcdx _123abcd 123abcd_ 123abcd8").is_empty());
+    assert!(find_all(scanner, "ä123abcd 123abcdé").is_empty());
+    assert!(find_all(scanner, "123abc 123ab!d").is_empty());
+}
+
+#[test]
+fn non_ascii() {
+    let scanner = CarettaIdScanner::new();
+    assert_eq!(
+        find_all(scanner, "見て「123abcd」"),
+        vec![("123abcd", 9..16)]
+    );
+    assert!(find_all(scanner, "１２３ａｂｃｄ").is_empty());
+}
+
+#[test]
+fn alias() {
+    let matches: Vec<_> = CarettaIdScanner::new()
+        .find_iter("OL2ABCD ol2aBcd")
+        .collect();
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].id(), "012abcd".parse().unwrap());
+    assert_eq!(matches[1].id(), "012abcd".parse().unwrap());
+}
+
+#[test]
+fn sigil() {
+    let scanner = CarettaIdScanner::new().sigil('#');
+    assert_eq!(
+        find_all(
+            scanner,
+            "#123abcd, 456wxyz, ##789abcd, a#789abcd, #789abcdz"
+        ),
+        vec![("#123abcd", 0..8), ("#789abcd", 20..28)]
+    );
+    assert_eq!(
+        find_all(CarettaIdScanner::new().sigil('→'), "→123abcd"),
+        vec![("→123abcd", 0..10)]
+    );
+    assert_eq!(scanner.find("text #123abcd").map(|m| m.start()), Some(5));
+    assert_eq!(scanner.find("text 123abcd"), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn replace() {
+    let scanner = CarettaIdScanner::new();
+    assert_eq!(
+        scanner.replace("see 0L2ABCD and 123abcd!", |m| m.id()),
+        "see 012abcd and 123abcd!"
+    );
+    assert_eq!(scanner.replace("no ids here", |m| m.id()), "no ids here");
+    assert_eq!(
+        scanner.replace("123abcd", |m| format!("<{}>", m.as_str())),
+        "<123abcd>"
+    );
+}
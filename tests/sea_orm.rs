@@ -86,3 +86,36 @@ async fn random() {
         .await;
     }
 }
+
+#[tokio::test]
+async fn out_of_range() {
+    use caretta_id::LossyCarettaId;
+    use sea_orm::{ConnectionTrait, Statement, sea_query::ValueType};
+
+    let oversized = CarettaId::MAX.to_u64() + 2;
+    let db = MockDatabase::new(DatabaseBackend::Sqlite)
+        .append_query_results([[std::collections::BTreeMap::from([(
+            "id",
+            Value::BigUnsigned(Some(oversized)),
+        )])]])
+        .into_connection();
+    let row = db
+        .query_one(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            r#"SELECT "id" FROM "caretta_id""#,
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(row.try_get::<CarettaId>("", "id").is_err());
+    assert_eq!(
+        row.try_get::<LossyCarettaId>("", "id").unwrap(),
+        LossyCarettaId(CarettaId::from_u64_lossy(oversized))
+    );
+    assert!(<CarettaId as ValueType>::try_from(Value::BigUnsigned(Some(oversized))).is_err());
+    assert_eq!(
+        <LossyCarettaId as ValueType>::try_from(Value::BigUnsigned(Some(oversized))).unwrap(),
+        LossyCarettaId(CarettaId::from_u64_lossy(oversized))
+    );
+}
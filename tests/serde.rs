@@ -36,3 +36,20 @@ fn nil_compact() {
 fn max_compact() {
     assert_tokens_compact(&CarettaId::MAX.compact(), &[Token::U64(0x7FFFFFFFF)]);
 }
+
+#[test]
+fn any() {
+    use caretta_id::AnyCarettaId;
+    fn assert_any(s: &'static str) {
+        let id: AnyCarettaId = s.parse().unwrap();
+        assert_ser_tokens(&id, &[Token::Str(s)]);
+        assert_ser_tokens(&id.compact(), &[Token::Str(s)]);
+        #[cfg(feature = "std")]
+        assert_de_tokens(&id, &[Token::Str(s)]);
+    }
+    assert_any("123abcd");
+    assert_any("abc");
+    assert_any("abc-def");
+    assert_any("abc-def-ghj");
+    assert_any("abc-def-ghj-kmn");
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "rusqlite-functions")]
+
+use caretta_id::CarettaId;
+use rusqlite::Connection;
+
+fn functions() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    caretta_id::register_sqlite_functions(&conn).unwrap();
+    conn
+}
+
+#[test]
+fn encode_function() {
+    let conn = functions();
+    let encode = |value: rusqlite::types::Value| {
+        conn.query_row("SELECT caretta_id_encode(?1)", [value], |row| {
+            row.get::<_, Option<String>>(0)
+        })
+    };
+    assert_eq!(
+        encode(rusqlite::types::Value::Integer(0))
+            .unwrap()
+            .as_deref(),
+        Some("0000000")
+    );
+    assert_eq!(
+        encode(rusqlite::types::Value::Integer(
+            CarettaId::MAX.to_u64() as i64
+        ))
+        .unwrap()
+        .as_deref(),
+        Some("zzzzzzz")
+    );
+    assert_eq!(encode(rusqlite::types::Value::Null).unwrap(), None);
+    assert!(
+        encode(rusqlite::types::Value::Integer(
+            CarettaId::MAX.to_u64() as i64 + 1
+        ))
+        .is_err()
+    );
+    assert!(encode(rusqlite::types::Value::Integer(-1)).is_err());
+    assert!(encode(rusqlite::types::Value::Text("123abcd".into())).is_err());
+}
+
+#[test]
+fn decode_function() {
+    let conn = functions();
+    let decode = |value: Option<&str>| {
+        conn.query_row("SELECT caretta_id_decode(?1)", [value], |row| {
+            row.get::<_, Option<CarettaId>>(0)
+        })
+    };
+    let id: CarettaId = "123abcd".parse().unwrap();
+    assert_eq!(decode(Some("123abcd")).unwrap(), Some(id));
+    assert_eq!(decode(Some("123-ABCD")).unwrap(), Some(id));
+    assert_eq!(decode(None).unwrap(), None);
+    assert!(decode(Some("123abc!")).is_err());
+    assert!(decode(Some("123abc")).is_err());
+
+    let roundtrip: String = conn
+        .query_row(
+            "SELECT caretta_id_encode(caretta_id_decode('zzzzzzz'))",
+            (),
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(roundtrip, "zzzzzzz");
+}
+
+#[test]
+fn is_valid_function() {
+    use rusqlite::types::Value;
+    let conn = functions();
+    let is_valid = |value: Value| {
+        conn.query_row("SELECT caretta_id_is_valid(?1)", [value], |row| {
+            row.get::<_, Option<bool>>(0)
+        })
+        .unwrap()
+    };
+    assert_eq!(is_valid(Value::Text("123abcd".into())), Some(true));
+    assert_eq!(is_valid(Value::Text("123-ABCD".into())), Some(true));
+    assert_eq!(is_valid(Value::Text("123abc!".into())), Some(false));
+    assert_eq!(is_valid(Value::Text("".into())), Some(false));
+    assert_eq!(is_valid(Value::Integer(0)), Some(true));
+    assert_eq!(
+        is_valid(Value::Integer(CarettaId::MAX.to_u64() as i64)),
+        Some(true)
+    );
+    assert_eq!(
+        is_valid(Value::Integer(CarettaId::MAX.to_u64() as i64 + 1)),
+        Some(false)
+    );
+    assert_eq!(is_valid(Value::Integer(-1)), Some(false));
+    assert_eq!(is_valid(Value::Real(1.0)), Some(false));
+    assert_eq!(is_valid(Value::Null), None);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_function() {
+    let conn = functions();
+    conn.execute("CREATE TABLE note (id INTEGER PRIMARY KEY, body TEXT)", ())
+        .unwrap();
+    for _ in 0..10 {
+        conn.execute(
+            "INSERT INTO note (id, body) VALUES (caretta_id_random(), 'hello')",
+            (),
+        )
+        .unwrap();
+    }
+    let valid: bool = conn
+        .query_row(
+            "SELECT count(*) = 10 AND min(caretta_id_is_valid(id)) FROM note",
+            (),
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert!(valid);
+}
+
+#[test]
+fn view() {
+    let path = std::env::temp_dir().join(format!(
+        "caretta-id-sqlite-functions-view-{}.sqlite3",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let conn = Connection::open(&path).unwrap();
+    caretta_id::register_sqlite_functions(&conn).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE note (id INTEGER PRIMARY KEY, body TEXT);
+         CREATE VIEW note_view AS SELECT caretta_id_encode(id) AS id, body FROM note;",
+    )
+    .unwrap();
+    let id: CarettaId = "123abcd".parse().unwrap();
+    conn.execute("INSERT INTO note (id, body) VALUES (?1, 'hello')", [id])
+        .unwrap();
+    let text: String = conn
+        .query_row("SELECT id FROM note_view WHERE body = 'hello'", (), |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(text, "123abcd");
+
+    // Other connection without the functions can read the table but not the view.
+    let other = Connection::open(&path).unwrap();
+    let int: CarettaId = other
+        .query_row("SELECT id FROM note", (), |row| row.get(0))
+        .unwrap();
+    assert_eq!(int, id);
+    let error = other
+        .query_row("SELECT id FROM note_view", (), |row| {
+            row.get::<_, String>(0)
+        })
+        .unwrap_err();
+    assert!(error.to_string().contains("no such function"));
+
+    drop(other);
+    drop(conn);
+    std::fs::remove_file(&path).unwrap();
+}
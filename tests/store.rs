@@ -0,0 +1,92 @@
+#![cfg(all(feature = "std", feature = "rand"))]
+
+use std::collections::{BTreeSet, HashSet};
+
+use caretta_id::*;
+
+fn assert_store<S: CarettaIdStore>(store: &mut S)
+where
+    S::Error: std::fmt::Debug,
+{
+    let id = store.generate_unique(10).unwrap();
+    assert!(store.contains_id(id).unwrap());
+    assert!(!store.reserve_id(id).unwrap());
+    assert!(!store.contains_id(CarettaId::NIL).unwrap());
+    assert!(store.reserve_id(CarettaId::NIL).unwrap());
+
+    let mut candidates = [CarettaId::NIL, id, CarettaId::MAX].into_iter();
+    assert_eq!(
+        store
+            .generate_unique_with(3, || candidates.next().unwrap())
+            .unwrap(),
+        CarettaId::MAX
+    );
+    assert!(matches!(
+        store.generate_unique_with(5, || CarettaId::MAX),
+        Err(GenerateUniqueError::AttemptsExhausted(5))
+    ));
+}
+
+#[test]
+fn hash_set() {
+    assert_store(&mut HashSet::new());
+}
+
+#[test]
+fn btree_set() {
+    assert_store(&mut BTreeSet::new());
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute(
+        r#"CREATE TABLE "my ""table""" (value TEXT, "id column" INTEGER UNIQUE NOT NULL)"#,
+        (),
+    )
+    .unwrap();
+    assert_store(&mut CarettaIdColumn::new(
+        &conn,
+        "my \"table\"",
+        "id column",
+    ));
+}
+
+#[cfg(feature = "redb")]
+#[test]
+fn redb() {
+    use redb::{Database, TableDefinition, backends::InMemoryBackend};
+    const TABLE: TableDefinition<CarettaId, ()> = TableDefinition::new("store");
+    let database = Database::builder()
+        .create_with_backend(InMemoryBackend::new())
+        .unwrap();
+    let write_txn = database.begin_write().unwrap();
+    assert_store(&mut write_txn.open_table(TABLE).unwrap());
+    write_txn.commit().unwrap();
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite_constraint_error() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute(
+        "CREATE TABLE note (id INTEGER PRIMARY KEY, body TEXT NOT NULL)",
+        (),
+    )
+    .unwrap();
+    let mut column = CarettaIdColumn::new(&conn, "note", "id");
+    assert!(matches!(
+        column.reserve_id(CarettaId::NIL),
+        Err(rusqlite::Error::SqliteFailure(_, _))
+    ));
+    assert!(matches!(
+        column.generate_unique(10),
+        Err(GenerateUniqueError::Store(_))
+    ));
+
+    // A column without `UNIQUE` constraint is rejected instead of reserving duplicated ids.
+    conn.execute("CREATE TABLE plain (id INTEGER)", ()).unwrap();
+    let mut column = CarettaIdColumn::new(&conn, "plain", "id");
+    assert!(column.reserve_id(CarettaId::NIL).is_err());
+}
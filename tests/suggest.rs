@@ -0,0 +1,50 @@
+use caretta_id::*;
+
+fn id(s: &str) -> CarettaId {
+    s.parse().unwrap()
+}
+
+#[test]
+fn confusable() {
+    let id = id("25b6g9d");
+    assert_eq!(id.typo_distance("25b6g9d"), 0);
+    assert_eq!(id.typo_distance("z5b6g9d"), 1);
+    assert_eq!(id.typo_distance("2sb6g9d"), 1);
+    assert_eq!(id.typo_distance("z586g9d"), 2);
+    assert_eq!(id.typo_distance("25bbg9d"), 1);
+    assert_eq!(id.typo_distance("25b6qg0"), 4);
+}
+
+#[test]
+fn insertion_and_deletion() {
+    let id = id("123abcd");
+    assert_eq!(id.typo_distance(""), 14);
+    assert_eq!(id.typo_distance("123abcdd"), 1);
+    assert_eq!(id.typo_distance("123abcde"), 2);
+    assert_eq!(id.typo_distance("12abcd"), 2);
+    assert_eq!(id.typo_distance("123 abcd"), 2);
+    assert_eq!(id.typo_distance("123-abcd"), 0);
+}
+
+#[test]
+fn transposition() {
+    let id = id("123abcd");
+    assert_eq!(id.typo_distance("213abcd"), 2);
+    assert_eq!(id.typo_distance("123abdc"), 2);
+    assert_eq!(id.typo_distance("213abdc"), 4);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn suggest() {
+    let index =
+        CarettaIdIndex::from_iter([id("123abcd"), id("123abce"), id("s23abcd"), id("zzzzzzz")]);
+    let suggestions: Vec<(CarettaId, u32)> = index
+        .suggest("523abcd", 2)
+        .into_iter()
+        .map(|x| (x.id(), x.distance()))
+        .collect();
+    assert_eq!(suggestions, vec![(id("s23abcd"), 1), (id("123abcd"), 2)]);
+    assert!(index.suggest("0000000", 4).is_empty());
+    assert!(CarettaIdIndex::new().suggest("123abcd", 14).is_empty());
+}
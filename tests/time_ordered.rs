@@ -0,0 +1,72 @@
+use caretta_id::*;
+
+#[test]
+fn out_of_range() {
+    let generator = TimeOrderedGenerator::new(1000, 60, 10).unwrap();
+    assert_eq!(
+        generator.from_unix_time(999, 0),
+        Err(Error::TimestampOutOfRange(999))
+    );
+    let last = 1000 + 60 * 1024 - 1;
+    assert!(generator.from_unix_time(last, u64::MAX).unwrap().is_max());
+    assert_eq!(
+        generator.from_unix_time(last + 1, 0),
+        Err(Error::TimestampOutOfRange(last + 1))
+    );
+}
+
+#[test]
+fn invalid_bit_width() {
+    assert_eq!(
+        TimeOrderedGenerator::new(0, 60, 0),
+        Err(Error::InvalidBitWidth(0))
+    );
+    assert_eq!(
+        TimeOrderedGenerator::new(0, 60, 35),
+        Err(Error::InvalidBitWidth(35))
+    );
+}
+
+#[test]
+fn invalid_resolution() {
+    assert_eq!(
+        TimeOrderedGenerator::new(0, 0, 20),
+        Err(Error::InvalidResolution(0))
+    );
+    assert_eq!(
+        TimeOrderedGenerator::new(0, u64::MAX, 10),
+        Err(Error::InvalidResolution(u64::MAX))
+    );
+    assert_eq!(
+        TimeOrderedGenerator::new(u64::MAX - 1022, 1, 10),
+        Err(Error::InvalidResolution(1))
+    );
+
+    // The last tick starts exactly at `u64::MAX`.
+    let generator = TimeOrderedGenerator::new(u64::MAX - 1023, 1, 10).unwrap();
+    let id = generator.from_unix_time(u64::MAX, 0).unwrap();
+    assert_eq!(generator.unix_time(id), u64::MAX);
+    #[cfg(feature = "std")]
+    assert_eq!(generator.system_time(id), None);
+}
+
+#[test]
+fn monotonic_overflow() {
+    let mut generator = TimeOrderedGenerator::new(0, 60, 33).unwrap().monotonic();
+    let mut last = generator.from_unix_time(0, 0).unwrap();
+    for _ in 0..3 {
+        let id = generator.from_unix_time(30, 0).unwrap();
+        assert!(last < id);
+        last = id;
+    }
+    assert_eq!(generator.from_unix_time(59, 0), Err(Error::MonotonicOverflow));
+    assert!(last < generator.from_unix_time(60, 0).unwrap());
+}
+
+#[test]
+fn monotonic_clock_backwards() {
+    let mut generator = TimeOrderedGenerator::new(0, 60, 20).unwrap().monotonic();
+    let first = generator.from_unix_time(600, 0).unwrap();
+    let second = generator.from_unix_time(0, 0).unwrap();
+    assert!(first < second);
+}
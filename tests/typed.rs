@@ -0,0 +1,81 @@
+use caretta_id::*;
+
+enum User {}
+impl CarettaIdKind for User {
+    const PREFIX: Option<&'static str> = Some("usr");
+}
+type UserId = TypedCarettaId<User>;
+
+enum Plain {}
+impl CarettaIdKind for Plain {}
+type PlainId = TypedCarettaId<Plain>;
+
+#[cfg(feature = "std")]
+#[test]
+fn string_conversion() {
+    assert_eq!(UserId::NIL.to_string(), "usr_0000000");
+    assert_eq!(PlainId::MAX.to_string(), "zzzzzzz");
+    assert_eq!("usr_zzzzzzz".parse::<UserId>().unwrap(), UserId::MAX);
+    assert_eq!("0000000".parse::<PlainId>().unwrap(), PlainId::NIL);
+}
+
+#[test]
+fn invalid_prefix() {
+    for s in ["0000000", "usr0000000", "usr-0000000", "user_0000000"] {
+        assert_eq!(
+            s.parse::<UserId>(),
+            Err(Error::InvalidPrefix { expected: "usr" })
+        );
+    }
+    assert_eq!("usr_000000".parse::<UserId>(), Err(Error::InvalidLength(6)));
+}
+
+#[test]
+fn integer_conversion() {
+    let id = UserId::try_from(0x123456789).unwrap();
+    assert_eq!(u64::from(id), 0x123456789);
+    assert_eq!(
+        UserId::try_from(0x800000000),
+        Err(Error::ValueOutOfRange(0x800000000))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_test::{Configure, Token, assert_ser_tokens, assert_tokens};
+    assert_ser_tokens(&UserId::NIL.readable(), &[Token::Str("usr_0000000")]);
+    #[cfg(feature = "std")]
+    serde_test::assert_de_tokens(&UserId::MAX.readable(), &[Token::Str("usr_zzzzzzz")]);
+    assert_tokens(&UserId::MAX.compact(), &[Token::U64(0x7FFFFFFFF)]);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let id = UserId::MAX;
+    let value: UserId = conn.query_row("SELECT ?1", [id], |row| row.get(0)).unwrap();
+    assert_eq!(value, id);
+}
+
+#[cfg(feature = "redb")]
+#[test]
+fn redb() {
+    use redb::{Database, ReadableDatabase, TableDefinition, backends::InMemoryBackend};
+    const TABLE: TableDefinition<UserId, CarettaId> = TableDefinition::new("typed");
+    let database = Database::builder()
+        .create_with_backend(InMemoryBackend::new())
+        .unwrap();
+    let key = UserId::MAX;
+    let write_txn = database.begin_write().unwrap();
+    write_txn
+        .open_table(TABLE)
+        .unwrap()
+        .insert(&key, &CarettaId::NIL)
+        .unwrap();
+    write_txn.commit().unwrap();
+    let read_txn = database.begin_read().unwrap();
+    let table = read_txn.open_table(TABLE).unwrap();
+    assert_eq!(table.get(key).unwrap().unwrap().value(), CarettaId::NIL);
+}
@@ -0,0 +1,40 @@
+#![allow(deprecated)]
+
+use caretta_id::*;
+
+fn assert_variant<T: CarettaIdVariant>(max: u64) {
+    assert_eq!(T::NIL.to_uint().into(), 0);
+    assert_eq!(T::MAX.to_uint().into(), max);
+    assert_eq!(max, (1u64 << T::BITS) - 1);
+    assert_eq!(T::from_uint(T::MAX.to_uint()).unwrap(), T::MAX);
+    assert_eq!(T::parse(&T::NIL.to_string()).unwrap(), T::NIL);
+    assert_eq!(T::parse(&T::MAX.to_string()).unwrap(), T::MAX);
+    assert_eq!(T::from_uint_lossy(T::MAX.to_uint()), T::MAX);
+    assert!(T::parse("").is_err());
+}
+
+#[test]
+fn carettaid() {
+    assert_variant::<CarettaId>(CarettaId::MAX.to_u64());
+    assert_eq!(
+        CarettaId::from_uint(CarettaId::MAX.to_u64() + 1),
+        Err(Error::ValueOutOfRange(CarettaId::MAX.to_u64() + 1))
+    );
+    assert_eq!(
+        <CarettaId as CarettaIdVariant>::from_uint_lossy(CarettaId::MAX.to_u64() + 1),
+        CarettaId::NIL
+    );
+}
+
+#[test]
+fn legacy() {
+    assert_variant::<CarettaIdS>(0x7fff);
+    assert_variant::<CarettaIdD>(0x3fff_ffff);
+    assert_variant::<CarettaIdT>(0x1fff_ffff_ffff);
+    assert_variant::<CarettaIdQ>(0x0fff_ffff_ffff_ffff);
+    assert!(<CarettaIdS as CarettaIdVariant>::from_uint(0x8000).is_err());
+    assert_eq!(
+        <CarettaIdD as CarettaIdVariant>::from_uint_lossy(0x4000_0000),
+        CarettaIdD::NIL
+    );
+}